@@ -0,0 +1,108 @@
+/*
+ * C interface of the Genius Square engine.
+ *
+ * Link against the shared library built by `cargo build --release --lib`
+ * (`libgeneral.so`, `libgeneral.dylib` or `general.dll`).
+ *
+ * All functions return a status code (GS_OK on success) and never unwind.
+ *
+ * Example:
+ *
+ *     const char *faces[7] = {"B1", "C4", "D6", "F1", "F2", "F3", "F5"};
+ *     gs_puzzle *puzzle = NULL;
+ *     uint8_t grid[GS_GRID_SIZE];
+ *     if (gs_puzzle_new(faces, 7, &puzzle) == GS_OK
+ *         && gs_puzzle_solve(puzzle, 5000, 0) == GS_OK
+ *         && gs_puzzle_copy_solution(puzzle, grid, sizeof grid) == GS_OK) {
+ *         // grid[6 * i + j] is the code of the cell in row i and column j
+ *     }
+ *     gs_puzzle_free(puzzle);
+ */
+
+#ifndef GENIUS_SQUARE_H
+#define GENIUS_SQUARE_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* ---------------------------------------------------------------- */
+/* STATUS CODES                                                     */
+/* ---------------------------------------------------------------- */
+
+#define GS_OK                   0
+#define GS_ERR_NULL_POINTER     1  /* a required pointer was null */
+#define GS_ERR_INVALID_FACES    2  /* the faces do not describe a roll of the dice */
+#define GS_ERR_NO_SOLUTION      3  /* the search finished without finding a solution */
+#define GS_ERR_LIMIT_REACHED    4  /* the search stopped at the time or node limit */
+#define GS_ERR_NOT_SOLVED       5  /* the puzzle has not been solved yet */
+#define GS_ERR_BUFFER_TOO_SMALL 6  /* the buffer is smaller than GS_GRID_SIZE */
+#define GS_ERR_UNSUPPORTED      7  /* the solver cannot handle the puzzle */
+#define GS_ERR_PANIC            99 /* internal error of the engine */
+
+/* ---------------------------------------------------------------- */
+/* CELL CODES                                                       */
+/* ---------------------------------------------------------------- */
+
+#define GS_GRID_HEIGHT 6
+#define GS_GRID_WIDTH  6
+#define GS_GRID_SIZE   (GS_GRID_HEIGHT * GS_GRID_WIDTH)
+
+#define GS_CELL_BLANK   0
+#define GS_CELL_BLOCK   1
+#define GS_CELL_PIECE_1 2
+#define GS_CELL_PIECE_2 3
+#define GS_CELL_PIECE_3 4
+#define GS_CELL_PIECE_4 5
+#define GS_CELL_PIECE_C 6
+#define GS_CELL_PIECE_L 7
+#define GS_CELL_PIECE_T 8
+#define GS_CELL_PIECE_X 9
+#define GS_CELL_PIECE_Z 10
+
+/* ---------------------------------------------------------------- */
+/* FUNCTIONS                                                        */
+/* ---------------------------------------------------------------- */
+
+/* Opaque handle of a puzzle */
+typedef struct GsPuzzle gs_puzzle;
+
+/*
+ * Creates a puzzle from the seven faces of a roll (e.g. "B1"), in any order.
+ * Returns GS_ERR_INVALID_FACES unless the dice can show the faces (one per die).
+ * The puzzle must be released with gs_puzzle_free.
+ */
+int32_t gs_puzzle_new(const char *const *faces, size_t num_faces, gs_puzzle **out);
+
+/* Releases a puzzle. Null pointers are ignored. */
+void gs_puzzle_free(gs_puzzle *puzzle);
+
+/*
+ * Solves the puzzle (limits of 0 are ignored).
+ * Returns GS_OK, GS_ERR_NO_SOLUTION or GS_ERR_LIMIT_REACHED.
+ */
+int32_t gs_puzzle_solve(gs_puzzle *puzzle, uint64_t timeout_ms, uint64_t max_nodes);
+
+/*
+ * Copies the solution row by row into a buffer of at least GS_GRID_SIZE bytes,
+ * one cell code (GS_CELL_*) per byte.
+ */
+int32_t gs_puzzle_copy_solution(const gs_puzzle *puzzle, uint8_t *buffer, size_t len);
+
+/*
+ * Counts all solutions of the puzzle (limits of 0 are ignored).
+ * If GS_ERR_LIMIT_REACHED is returned, the count is 0.
+ */
+int32_t gs_puzzle_count(const gs_puzzle *puzzle, uint64_t timeout_ms, uint64_t max_nodes, uint64_t *count);
+
+/* Describes a status code. The string is static and must not be freed. */
+const char *gs_status_message(int32_t code);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* GENIUS_SQUARE_H */
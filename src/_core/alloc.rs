@@ -0,0 +1,67 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Wrapper around the system allocator which counts allocations.
+///
+/// To use it, a binary has to register it via
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOC: CountingAllocator = CountingAllocator;
+/// ```
+#[allow(unused)]
+pub struct CountingAllocator;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        INSTALLED.store(true, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Number of allocations performed so far,
+/// or `None` if the [`CountingAllocator`] is not registered.
+#[allow(unused)]
+pub fn allocation_count() -> Option<u64> {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        return None;
+    }
+    return Some(ALLOCATIONS.load(Ordering::Relaxed));
+}
@@ -1,3 +1,7 @@
+pub mod alloc;
 pub mod errors;
 pub mod rand;
 pub mod strings;
+
+#[cfg(test)]
+pub mod tests_rand;
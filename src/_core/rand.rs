@@ -4,28 +4,148 @@
 
 use rand;
 use rand::SeedableRng;
+use rand::TryRngCore;
+use rand::rngs::OsRng;
 use rand_chacha::ChaCha8Rng;
+use sha2::Digest;
+use sha2::Sha256;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+/// Prefix which marks a string as a replay token
+pub const REPLAY_TOKEN_PREFIX: &str = "0x";
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Full 32-byte seed of a ChaCha generator.
+///
+/// Seeds can be obtained from
+///
+/// - replay tokens (`0x` followed by 64 hex digits), which are restored exactly,
+/// - numbers (decimal digits only),
+/// - arbitrary strings, which are hashed into the full seed,
+/// - fresh entropy from the operating system.
+///
+/// Every seed has a replay token (see [`Seed::to_token`]),
+/// from which the same seed can be recovered.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Seed {
+    bytes: [u8; 32],
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl Seed {
+    #[allow(unused)]
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self { bytes }
+    }
+
+    /// Draws a fresh seed from the operating system.
+    pub fn from_entropy() -> Self {
+        let mut bytes = [0u8; 32];
+        OsRng.try_fill_bytes(&mut bytes).expect("could not obtain entropy from the operating system");
+        return Self { bytes };
+    }
+
+    /// Expands a numeric seed into a full seed.
+    pub fn from_u64(x: u64) -> Self {
+        let bytes = ChaCha8Rng::seed_from_u64(x).get_seed();
+        return Self { bytes };
+    }
+
+    /// Hashes a string into a full seed.
+    pub fn from_text(text: &str) -> Self {
+        let bytes: [u8; 32] = Sha256::digest(text.as_bytes()).into();
+        return Self { bytes };
+    }
+
+    /// Interprets a string as a replay token, a number or a text (in that order).
+    pub fn parse(text: &str) -> Self {
+        if let Some(seed) = Self::from_token(text) {
+            return seed;
+        }
+        if let Ok(x) = text.parse::<u64>() {
+            return Self::from_u64(x);
+        }
+        return Self::from_text(text);
+    }
+
+    /// Recovers a seed from a replay token, if the string is one.
+    pub fn from_token(text: &str) -> Option<Self> {
+        let hex = text.strip_prefix(REPLAY_TOKEN_PREFIX)?;
+        if hex.len() != 64 || !hex.is_ascii() {
+            return None;
+        }
+        let mut bytes = [0u8; 32];
+        for (k, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * k..2 * k + 2], 16).ok()?;
+        }
+        return Some(Self { bytes });
+    }
+
+    /// Replay token from which the seed can be restored exactly.
+    pub fn to_token(&self) -> String {
+        let hex: String = self.bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+        return format!("{REPLAY_TOKEN_PREFIX}{hex}");
+    }
+
+    #[allow(unused)]
+    pub fn get_bytes(&self) -> [u8; 32] {
+        self.bytes
+    }
+
+    pub fn to_rng(&self) -> ChaCha8Rng {
+        ChaCha8Rng::from_seed(self.bytes)
+    }
+
+    /// Creates an independent child stream for a subsystem.
+    ///
+    /// Child streams share the key of the parent seed
+    /// but use the stream determined by the label,
+    /// so different labels never consume each other's random numbers.
+    #[allow(unused)]
+    pub fn derive(&self, label: &str) -> ChaCha8Rng {
+        let digest = Sha256::digest(label.as_bytes());
+        let mut stream = [0u8; 8];
+        stream.copy_from_slice(&digest[..8]);
+        let mut rng = self.to_rng();
+        rng.set_stream(u64::from_le_bytes(stream));
+        return rng;
+    }
+}
+
+impl Display for Seed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.to_token())
+    }
+}
 
 /// ----------------------------------------------------------------
 /// METHODS
 /// ----------------------------------------------------------------
 
+/// Determines the seed from an optional string (cf. [`Seed::parse`]),
+/// falling back to fresh entropy.
 #[allow(unused)]
-pub fn seed_rng(x: Option<String>) -> ChaCha8Rng {
+pub fn seed_from_option(x: Option<String>) -> Seed {
     match x {
-        Some(seed_str) => {
-            // string -> bytes
-            let mut seed = [0u8; 32];
-            let seed_bytes = seed_str.as_bytes();
-            let len = seed_bytes.len().min(32);
-            seed[..len].copy_from_slice(&seed_bytes[..len]);
-
-            // create RNG
-            let rng = ChaCha8Rng::from_seed(seed);
-            return rng;
-        },
-        None => {
-            return ChaCha8Rng::from_os_rng();
-        },
+        Some(seed_str) => Seed::parse(&seed_str),
+        None => Seed::from_entropy(),
     }
 }
+
+#[allow(unused)]
+pub fn seed_rng(x: Option<String>) -> ChaCha8Rng {
+    seed_from_option(x).to_rng()
+}
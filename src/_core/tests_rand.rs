@@ -0,0 +1,64 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use rand::Rng;
+
+use super::rand::*;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_seeds_do_not_collide() {
+        let prefix = "a".repeat(32);
+        let seed1 = Seed::parse(&format!("{prefix}1"));
+        let seed2 = Seed::parse(&format!("{prefix}2"));
+        assert_ne!(seed1, seed2);
+    }
+
+    #[test]
+    fn test_short_seeds_are_not_padded() {
+        let seed = Seed::parse("a");
+        assert_ne!(seed.get_bytes()[1..], [0u8; 31]);
+    }
+
+    #[test]
+    fn test_numeric_seeds() {
+        assert_eq!(Seed::parse("1234"), Seed::from_u64(1234));
+        assert_ne!(Seed::parse("1234"), Seed::from_text("1234"));
+    }
+
+    #[test]
+    fn test_token_roundtrip() {
+        let seed = Seed::from_entropy();
+        let token = seed.to_token();
+        assert_eq!(Seed::parse(&token), seed);
+        let x: u64 = seed.to_rng().random();
+        let y: u64 = seed_rng(Some(token)).random();
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn test_invalid_token_is_text() {
+        assert_eq!(Seed::parse("0xabc"), Seed::from_text("0xabc"));
+    }
+
+    #[test]
+    fn test_derived_streams() {
+        let seed = Seed::parse("1234");
+        let x1: u64 = seed.derive("dice").random();
+        let x2: u64 = seed.derive("dice").random();
+        let y: u64 = seed.derive("players").random();
+        let z: u64 = seed.to_rng().random();
+        assert_eq!(x1, x2);
+        assert_ne!(x1, y);
+        assert_ne!(x1, z);
+    }
+}
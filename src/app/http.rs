@@ -0,0 +1,330 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+/// Maximal size of the request line and headers
+const MAX_HEAD_BYTES: usize = 16 * 1024;
+/// Maximal size of a request body
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+/// Maximal time for refusing a connection, which blocks the accepting thread
+const REFUSAL_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Minimal HTTP/1.1 request
+#[derive(Clone, Debug, Default)]
+pub struct HttpRequest {
+    pub method: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+/// Minimal HTTP/1.1 response
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub content_type: String,
+    pub body: Vec<u8>,
+}
+
+/// Settings of the server
+#[derive(Clone, Debug)]
+pub struct HttpServerOptions {
+    /// Timeout for reading a whole request and for writing a whole response
+    pub timeout: Duration,
+    /// Maximal number of connections handled at the same time
+    pub max_connections: usize,
+}
+
+/// Connection counted towards the maximal number of connections,
+/// which is released when dropped (also if the handler panics)
+struct ConnectionSlot {
+    active: Arc<AtomicUsize>,
+}
+
+/// Stream whose reads and writes fail once a deadline has passed
+/// (the timeouts of sockets only bound every single read or write)
+struct DeadlineStream {
+    stream: TcpStream,
+    deadline: Option<Instant>,
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl HttpRequest {
+    /// Parses a request from a stream.
+    pub fn read_from<R: BufRead>(reader: &mut R) -> Result<Self, String> {
+        // request line
+        let mut head_size = 0;
+        let line = read_head_line(reader, &mut head_size)?;
+        let mut parts = line.split_whitespace();
+        let method = parts.next().ok_or("Missing method.")?.to_uppercase();
+        let target = parts.next().ok_or("Missing request target.")?;
+        let version = parts.next().ok_or("Missing HTTP version.")?;
+        if !version.starts_with("HTTP/1.") {
+            return Err(format!("Unsupported version {version}."));
+        }
+        let (path, query) = match target.split_once('?') {
+            Some((path, query)) => (path.to_string(), parse_query(query)),
+            None => (target.to_string(), HashMap::new()),
+        };
+
+        // headers
+        let mut headers: HashMap<String, String> = HashMap::new();
+        loop {
+            let line = read_head_line(reader, &mut head_size)?;
+            if line.is_empty() {
+                break;
+            }
+            let (key, value) = line.split_once(':').ok_or_else(|| format!("Invalid header {line}."))?;
+            headers.insert(key.trim().to_lowercase(), value.trim().to_string());
+        }
+
+        // body
+        let length: usize = match headers.get("content-length") {
+            Some(value) => value.parse().map_err(|_| format!("Invalid content length {value}."))?,
+            None => 0,
+        };
+        if length > MAX_BODY_BYTES {
+            return Err(format!("Body exceeds {MAX_BODY_BYTES} bytes."));
+        }
+        let mut body = vec![0u8; length];
+        reader.read_exact(&mut body).map_err(|err| err.to_string())?;
+
+        return Ok(Self { method, path, query, headers, body });
+    }
+
+    pub fn body_as_str(&self) -> Result<&str, String> {
+        std::str::from_utf8(&self.body).map_err(|err| err.to_string())
+    }
+}
+
+impl HttpResponse {
+    pub fn json(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            content_type: "application/json".to_string(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    /// Response with body `{"error": message}`
+    pub fn error(status: u16, message: &str) -> Self {
+        let body = serde_json::json!({ "error": message });
+        Self::json(status, &body.to_string())
+    }
+
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            self.status,
+            reason_phrase(self.status),
+            self.content_type,
+            self.body.len(),
+        );
+        writer.write_all(head.as_bytes())?;
+        writer.write_all(&self.body)?;
+        writer.flush()
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.active.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl DeadlineStream {
+    /// A timeout too long to be represented as an instant imposes no deadline.
+    fn new(stream: TcpStream, timeout: Duration) -> Self {
+        let deadline = Instant::now().checked_add(timeout);
+        return Self { stream, deadline };
+    }
+
+    /// Time left until the deadline (`None` without a deadline)
+    fn remaining(&self) -> std::io::Result<Option<Duration>> {
+        let Some(deadline) = self.deadline else {
+            return Ok(None);
+        };
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(Error::new(ErrorKind::TimedOut, "Deadline exceeded."));
+        }
+        return Ok(Some(remaining));
+    }
+}
+
+impl Read for DeadlineStream {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        let remaining = self.remaining()?;
+        self.stream.set_read_timeout(remaining)?;
+        self.stream.read(buffer)
+    }
+}
+
+impl Write for DeadlineStream {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        let remaining = self.remaining()?;
+        self.stream.set_write_timeout(remaining)?;
+        self.stream.write(buffer)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+impl Default for HttpServerOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            max_connections: 16,
+        }
+    }
+}
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Serves requests on the listener until the process ends.
+///
+/// Every connection is handled on its own thread and answers a single request,
+/// which has to be received within the timeout (and the response sent within another).
+/// Connections beyond the maximal number are refused with status 503.
+pub fn serve<F>(
+    listener: TcpListener,
+    options: &HttpServerOptions,
+    handler: F,
+)
+where
+    F: Fn(&HttpRequest) -> HttpResponse + Send + Sync + 'static,
+{
+    let handler = Arc::new(handler);
+    let active = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        let mut stream = match stream {
+            Ok(stream) => stream,
+            Err(_) => continue,
+        };
+        if active.fetch_add(1, Ordering::SeqCst) >= options.max_connections {
+            active.fetch_sub(1, Ordering::SeqCst);
+            // NOTE: the refusal is written on the accepting thread, so a client which does not read must not block it
+            let _ = stream.set_write_timeout(Some(REFUSAL_TIMEOUT.min(options.timeout)));
+            let _ = HttpResponse::error(503, "Too many connections.").write_to(&mut stream);
+            continue;
+        }
+        let handler = Arc::clone(&handler);
+        let slot = ConnectionSlot {active: Arc::clone(&active)};
+        let timeout = options.timeout;
+        thread::spawn(move || {
+            let _slot = slot;
+            handle_connection(stream, handler.as_ref(), timeout);
+        });
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+fn handle_connection<F>(stream: TcpStream, handler: &F, timeout: Duration)
+where
+    F: Fn(&HttpRequest) -> HttpResponse,
+{
+    let mut reader = BufReader::new(DeadlineStream::new(stream, timeout));
+    let request = HttpRequest::read_from(&mut reader);
+    let response = match request {
+        // NOTE: a panicking handler must not take the connection down without a response
+        Ok(request) => panic::catch_unwind(AssertUnwindSafe(|| handler(&request)))
+            .unwrap_or_else(|_| HttpResponse::error(500, "Internal server error.")),
+        Err(err) => HttpResponse::error(400, &err),
+    };
+    let mut writer = DeadlineStream::new(reader.into_inner().stream, timeout);
+    let _ = response.write_to(&mut writer);
+}
+
+/// Reads a line of the request head (without the line break)
+fn read_head_line<R: BufRead>(reader: &mut R, head_size: &mut usize) -> Result<String, String> {
+    let mut buffer: Vec<u8> = vec![];
+    let mut limited = reader.take((MAX_HEAD_BYTES - *head_size) as u64);
+    let n = limited.read_until(b'\n', &mut buffer).map_err(|err| err.to_string())?;
+    *head_size += n;
+    if n == 0 || !buffer.ends_with(b"\n") {
+        return Err("Incomplete request head.".to_string());
+    }
+    let line = String::from_utf8(buffer).map_err(|err| err.to_string())?;
+    return Ok(line.trim_end_matches(['\r', '\n']).to_string());
+}
+
+/// Parses `key=value&...` pairs, decoding `+` and `%XX` escapes
+fn parse_query(query: &str) -> HashMap<String, String> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode_component(key), decode_component(value))
+        })
+        .collect()
+}
+
+fn decode_component(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut result: Vec<u8> = vec![];
+    let mut k = 0;
+    while k < bytes.len() {
+        match bytes[k] {
+            b'+' => result.push(b' '),
+            b'%' if k + 2 < bytes.len() && bytes[k + 1].is_ascii_hexdigit() && bytes[k + 2].is_ascii_hexdigit() => {
+                let hex = std::str::from_utf8(&bytes[k + 1..k + 3]).unwrap();
+                result.push(u8::from_str_radix(hex, 16).unwrap());
+                k += 2;
+            },
+            byte => result.push(byte),
+        }
+        k += 1;
+    }
+    return String::from_utf8_lossy(&result).to_string();
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        422 => "Unprocessable Entity",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
@@ -1,3 +1,7 @@
 /// Submodules for app-level methods
 
+pub mod http;
 pub mod messages;
+
+#[cfg(test)]
+pub mod tests_http;
@@ -0,0 +1,129 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use super::http::*;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_with_query() {
+        let raw = "GET /roll?seed=my%20seed&x=a+b HTTP/1.1\r\nHost: localhost\r\n\r\n";
+        let request = HttpRequest::read_from(&mut Cursor::new(raw)).unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/roll");
+        assert_eq!(request.query.get("seed"), Some(&"my seed".to_string()));
+        assert_eq!(request.query.get("x"), Some(&"a b".to_string()));
+        assert_eq!(request.headers.get("host"), Some(&"localhost".to_string()));
+        assert!(request.body.is_empty());
+    }
+
+    #[test]
+    fn test_post_with_body() {
+        let raw = "POST /solve HTTP/1.1\r\nContent-Length: 13\r\n\r\n{\"roll\": []}\n";
+        let request = HttpRequest::read_from(&mut Cursor::new(raw)).unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.body_as_str(), Ok("{\"roll\": []}\n"));
+    }
+
+    #[test]
+    fn test_incomplete_request() {
+        let raw = "POST /solve HTTP/1.1\r\nContent-Length: 20\r\n\r\n{}";
+        assert!(HttpRequest::read_from(&mut Cursor::new(raw)).is_err());
+        let raw = "GET / HTTP/1.1\r\n";
+        assert!(HttpRequest::read_from(&mut Cursor::new(raw)).is_err());
+    }
+
+    #[test]
+    fn test_response() {
+        let response = HttpResponse::json(200, "{}");
+        let mut buffer: Vec<u8> = vec![];
+        response.write_to(&mut buffer).unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+        assert_eq!(text, "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}");
+    }
+
+    #[test]
+    fn test_panicking_handler() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let options = HttpServerOptions {max_connections: 2, ..HttpServerOptions::default()};
+        thread::spawn(move || {
+            serve(listener, &options, |request| {
+                if request.path == "/panic" {
+                    panic!("handler failed");
+                }
+                HttpResponse::json(200, "{}")
+            });
+        });
+        // the connections of panicking handlers are released
+        for _ in 0..5 {
+            assert!(get(&address, "/panic").starts_with("HTTP/1.1 500 "));
+        }
+        assert!(get(&address, "/").starts_with("HTTP/1.1 200 "));
+    }
+
+    #[test]
+    fn test_slow_request() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let options = HttpServerOptions {timeout: Duration::from_millis(300), ..HttpServerOptions::default()};
+        thread::spawn(move || {
+            serve(listener, &options, |_| HttpResponse::json(200, "{}"));
+        });
+        // a client sending a byte every now and then may not hold the connection beyond the timeout
+        let mut stream = TcpStream::connect(&address).unwrap();
+        let mut writer = stream.try_clone().unwrap();
+        thread::spawn(move || {
+            let _ = write!(writer, "GET / HTTP/1.1\r\n");
+            for _ in 0..20 {
+                thread::sleep(Duration::from_millis(100));
+                let _ = write!(writer, "x");
+            }
+        });
+        let t0 = Instant::now();
+        let mut response: Vec<u8> = vec![];
+        // NOTE: the server may reset the connection, as it does not read the rest of the request
+        if stream.read_to_end(&mut response).is_ok() {
+            assert!(response.starts_with(b"HTTP/1.1 400 "));
+        }
+        assert!(t0.elapsed() < Duration::from_millis(1500));
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Sends a request and returns the raw response,
+/// retrying while the server is busy (the connection of the previous request may not be released yet)
+fn get(address: &str, path: &str) -> String {
+    let mut response = String::new();
+    for _ in 0..100 {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(stream, "GET {path} HTTP/1.1\r\n\r\n").unwrap();
+        response.clear();
+        stream.read_to_string(&mut response).unwrap();
+        if !response.starts_with("HTTP/1.1 503 ") {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    return response;
+}
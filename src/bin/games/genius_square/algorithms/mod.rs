@@ -1,3 +0,0 @@
-/// Algorithms used to solve a game state.
-
-pub mod solve;
@@ -0,0 +1,162 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::fs;
+use std::process;
+
+use general::_core;
+
+use general::games::genius_square::models::dice::analysis::DiceAnalysis;
+use general::games::genius_square::models::dice::analysis::pretty_table;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+const DEFAULT_SEED: &str = "analyse";
+const DEFAULT_NUM_SAMPLES: usize = 100000;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Reports the probabilities of blocked cells for the dice of the game
+/// (or for modified dice) and checks them by sampling.
+///
+/// Usage:
+///
+/// ```text
+/// analyse [--die K=A1,B2,...]... [--samples N] [--seed S] [--joint FILE]
+/// ```
+///
+/// Each `--die` replaces the faces of die `K` (counted from `1`).
+/// With `--joint` the joint probabilities of all pairs of cells are written as CSV.
+pub fn feature_analyse(args: &[String]) {
+    let settings = match AnalyseSettings::from_args(args) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2);
+        }
+    };
+
+    let mut analysis = DiceAnalysis::new();
+    for (k, faces) in settings.dice.iter() {
+        analysis = analysis.with_die(*k, faces.clone()).unwrap_or_else(|err| {
+            eprintln!("{err}");
+            process::exit(2);
+        });
+    }
+
+    println!("\nDice:");
+    for (k, die) in analysis.get_dice().iter().enumerate() {
+        println!("{:>2}: {}", k + 1, die.join(" "));
+    }
+
+    println!("\nProbability of each cell being blocked:\n\n{}", analysis.pretty_cells());
+
+    let never = analysis.get_never_blocked();
+    if never.is_empty() {
+        println!("\nEvery cell can be blocked.");
+    } else {
+        println!("\nCells which are never blocked: {}.", never.join(", "));
+    }
+
+    let skews = analysis.get_skews();
+    if !skews.is_empty() {
+        println!("\nRepeated faces:");
+        for skew in skews.iter() {
+            println!(
+                "- die {}: {} on {} faces, probability {:.1}% (instead of {:.1}% with distinct faces)",
+                skew.die + 1,
+                skew.face,
+                skew.multiplicity,
+                skew.probability * 100.,
+                skew.probability_unskewed * 100.,
+            );
+        }
+    }
+
+    if let Some(path) = &settings.path_joint {
+        let mut lines = vec!["cell1,cell2,joint,covariance".to_string()];
+        for (cell1, cell2, joint, covariance) in analysis.get_pairs() {
+            lines.push(format!("{cell1},{cell2},{joint},{covariance}"));
+        }
+        fs::write(path, lines.join("\n") + "\n").unwrap_or_else(|err| {
+            eprintln!("Could not write {path}: {err}");
+            process::exit(1);
+        });
+        println!("\nJoint probabilities saved to {path}.");
+    }
+
+    // compare against sampled rolls
+    let mut rng = _core::rand::seed_rng(Some(settings.seed.clone()));
+    let report = analysis.monte_carlo(settings.num_samples, &mut rng);
+    println!(
+        "\nSampled frequencies over {} rolls (seed: {}):\n\n{}",
+        report.num_rolls,
+        settings.seed,
+        pretty_table(&report.cell_frequencies),
+    );
+    println!(
+        "\nLargest deviation from the exact probabilities: {:.2}% (cells), {:.2}% (pairs of cells).",
+        report.max_cell_error * 100.,
+        report.max_joint_error * 100.,
+    );
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Settings of the analyse command
+struct AnalyseSettings {
+    /// replaced dice (indexed from `0`)
+    dice: Vec<(usize, Vec<String>)>,
+    seed: String,
+    num_samples: usize,
+    path_joint: Option<String>,
+}
+
+impl AnalyseSettings {
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut settings = Self {
+            dice: vec![],
+            seed: DEFAULT_SEED.to_string(),
+            num_samples: DEFAULT_NUM_SAMPLES,
+            path_joint: None,
+        };
+        let mut it = args.iter();
+        while let Some(flag) = it.next() {
+            let value = it.next().ok_or_else(|| format!("Missing value for {flag}."))?;
+            match flag.as_str() {
+                "--die" => {
+                    let (k, faces) = value
+                        .split_once('=')
+                        .ok_or_else(|| format!("Die {value} must be of the form K=CELL,CELL,..."))?;
+                    let k: usize = k.trim().parse().map_err(|_| format!("Invalid die {k}."))?;
+                    if k == 0 {
+                        return Err("Dice are counted from 1.".to_string());
+                    }
+                    let faces = faces.split(',').map(|face| face.trim().to_string()).collect();
+                    settings.dice.push((k - 1, faces));
+                },
+                "--samples" => {
+                    settings.num_samples = value.parse().map_err(|_| format!("Invalid number of samples {value}."))?;
+                },
+                "--seed" => {
+                    settings.seed = value.clone();
+                },
+                "--joint" => {
+                    settings.path_joint = Some(value.clone());
+                },
+                _ => {
+                    return Err(format!("Unknown option {flag}."));
+                },
+            }
+        }
+        return Ok(settings);
+    }
+}
+
@@ -0,0 +1,186 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::fs;
+use std::process;
+use std::time::Duration;
+
+use general::_core;
+use general::_core::alloc::allocation_count;
+
+use general::games::genius_square::models::bench::models::BenchRecord;
+use general::games::genius_square::models::bench::models::BenchReport;
+use general::games::genius_square::models::bench::models::BenchSample;
+use general::games::genius_square::models::board::models::GameBoard;
+use general::games::genius_square::models::dice::methods::block_from_roll;
+use general::games::genius_square::models::dice::methods::roll_dice;
+use general::games::genius_square::models::solver::models::SolveOptions;
+use general::games::genius_square::models::solver::models::SolveStatus;
+use general::games::genius_square::models::solver::models::Solver;
+use general::games::genius_square::algorithms::solvers::SOLVERS;
+use general::games::genius_square::algorithms::solvers::get_solver;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+const DEFAULT_SEED: &str = "bench";
+const DEFAULT_NUM_ROLLS: usize = 20;
+const DEFAULT_THRESHOLD: f64 = 0.1;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Solves a deterministic corpus of rolls with every strategy
+/// (or the given ones, see `SOLVERS`) and reports timing statistics.
+///
+/// Usage:
+///
+/// ```text
+/// bench [--seed S] [--rolls N] [--solvers NAME,...] [--timeout SECS] [--out FILE] [--baseline FILE] [--threshold PERCENT]
+/// ```
+pub fn feature_bench(args: &[String]) {
+    let settings = match BenchSettings::from_args(args) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2);
+        }
+    };
+
+    // Generate the corpus
+    let mut rng = _core::rand::seed_rng(Some(settings.seed.clone()));
+    let corpus: Vec<Vec<String>> = (0..settings.num_rolls).map(|_| roll_dice(&mut rng)).collect();
+    println!("\nBenchmark over {} rolls (seed: {}).\n", corpus.len(), settings.seed);
+
+    // Run every strategy over the corpus
+    let mut records: Vec<BenchRecord> = vec![];
+    for solver in settings.solvers.iter() {
+        let samples: Vec<BenchSample> = corpus
+            .iter()
+            .map(|faces| {
+                let block = block_from_roll(faces);
+                let board = GameBoard::new(&block);
+                let mut options = SolveOptions::new();
+                if let Some(timeout) = settings.timeout {
+                    options = options.with_timeout(timeout);
+                }
+                let allocs0 = allocation_count();
+                let outcome = solver.solve(&board, &options);
+                let allocs1 = allocation_count();
+                return BenchSample {
+                    elapsed: outcome.stats.elapsed,
+                    nodes: outcome.stats.nodes,
+                    solved: outcome.status == SolveStatus::Solved,
+                    allocations: allocs0.zip(allocs1).map(|(a0, a1)| a1 - a0),
+                };
+            })
+            .collect();
+        let record = BenchRecord::from_samples(solver.name(), &samples);
+        println!("{record}");
+        records.push(record);
+    }
+
+    let report = BenchReport {
+        seed: settings.seed.clone(),
+        num_rolls: settings.num_rolls,
+        records,
+    };
+
+    if let Some(path) = &settings.path_out {
+        fs::write(path, report.to_json()).unwrap_or_else(|err| {
+            eprintln!("Could not write {path}: {err}");
+            process::exit(1);
+        });
+        println!("\nResults saved to {path}.");
+    }
+
+    // Compare against an earlier run
+    if let Some(path) = &settings.path_baseline {
+        let baseline = fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|text| BenchReport::from_json(&text))
+            .unwrap_or_else(|err| {
+                eprintln!("Could not read {path}: {err}");
+                process::exit(1);
+            });
+        if baseline.seed != report.seed || baseline.num_rolls != report.num_rolls {
+            println!("\nWARNING: baseline {path} was computed over a different corpus.");
+        }
+        let regressions = report.compare(&baseline, settings.threshold);
+        if regressions.is_empty() {
+            println!("\nNo regressions above {:.0}% relative to {path}.", settings.threshold * 100.);
+        } else {
+            println!("\nRegressions above {:.0}% relative to {path}:", settings.threshold * 100.);
+            for regression in regressions.iter() {
+                println!("- {regression}");
+            }
+            process::exit(1);
+        }
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Settings of the bench command
+struct BenchSettings {
+    seed: String,
+    num_rolls: usize,
+    solvers: Vec<&'static dyn Solver>,
+    timeout: Option<Duration>,
+    path_out: Option<String>,
+    path_baseline: Option<String>,
+    threshold: f64,
+}
+
+impl BenchSettings {
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut settings = Self {
+            seed: DEFAULT_SEED.to_string(),
+            num_rolls: DEFAULT_NUM_ROLLS,
+            solvers: SOLVERS.to_vec(),
+            timeout: None,
+            path_out: None,
+            path_baseline: None,
+            threshold: DEFAULT_THRESHOLD,
+        };
+        let mut it = args.iter();
+        while let Some(flag) = it.next() {
+            let value = it.next().ok_or_else(|| format!("Missing value for {flag}."))?;
+            match flag.as_str() {
+                "--seed" => {
+                    settings.seed = value.clone();
+                },
+                "--rolls" => {
+                    settings.num_rolls = value.parse().map_err(|_| format!("Invalid number of rolls {value}."))?;
+                },
+                "--solvers" => {
+                    settings.solvers = value.split(',').map(get_solver).collect::<Result<_, _>>()?;
+                },
+                "--timeout" => {
+                    let secs: f64 = value.parse().map_err(|_| format!("Invalid timeout {value}."))?;
+                    let timeout = Duration::try_from_secs_f64(secs).map_err(|_| format!("Invalid timeout {value}."))?;
+                    settings.timeout = Some(timeout);
+                },
+                "--out" => {
+                    settings.path_out = Some(value.clone());
+                },
+                "--baseline" => {
+                    settings.path_baseline = Some(value.clone());
+                },
+                "--threshold" => {
+                    let percent: f64 = value.parse().map_err(|_| format!("Invalid threshold {value}."))?;
+                    settings.threshold = percent / 100.;
+                },
+                _ => {
+                    return Err(format!("Unknown option {flag}."));
+                },
+            }
+        }
+        return Ok(settings);
+    }
+}
@@ -0,0 +1,146 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::fs;
+use std::process;
+
+use general::_core;
+
+use general::games::genius_square::algorithms::cnf::encode_cnf;
+use general::games::genius_square::algorithms::validate::validate_solution;
+use general::games::genius_square::models::board::methods::block_from_cells;
+use general::games::genius_square::models::board::models::GameBoard;
+use general::games::genius_square::models::dice::methods::roll_dice;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Exports a roll as a SAT problem in the DIMACS CNF format,
+/// or imports the model found by a SAT solver for that roll.
+///
+/// Usage:
+///
+/// ```text
+/// cnf [--roll B1,C4,...] [--seed S] [--out FILE]
+/// cnf [--roll B1,C4,...] [--seed S] --model FILE
+/// ```
+///
+/// Without `--out` the formula is written to stdout,
+/// so that it can be piped into a solver.
+pub fn feature_cnf(args: &[String]) {
+    let settings = match CnfSettings::from_args(args) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2);
+        }
+    };
+
+    // determine the roll
+    let faces = match &settings.roll {
+        Some(faces) => faces.clone(),
+        None => {
+            let seed = _core::rand::seed_from_option(settings.seed.clone());
+            eprintln!("Replay token: {seed}");
+            roll_dice(&mut seed.derive("dice"))
+        },
+    };
+    let block = block_from_cells(&faces).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    let board = GameBoard::new(&block);
+    eprintln!("Roll: {}.", faces.join(", "));
+    let encoding = encode_cnf(&board);
+
+    match &settings.path_model {
+        // import the model of a solver
+        Some(path) => {
+            let text = fs::read_to_string(path).unwrap_or_else(|err| {
+                eprintln!("Could not read {path}: {err}");
+                process::exit(1);
+            });
+            let solution = encoding.decode_model(&text).unwrap_or_else(|err| {
+                eprintln!("{err}");
+                process::exit(1);
+            });
+            println!("\nSolution:\n{}\n", solution.pretty());
+            match validate_solution(&solution) {
+                Ok(()) => println!("The model is a valid solution."),
+                Err(errors) => {
+                    println!("The model is not a valid solution:");
+                    for error in errors {
+                        println!("- {error}");
+                    }
+                    process::exit(1);
+                },
+            }
+        },
+        // export the formula
+        None => {
+            let formula = encoding.get_formula();
+            let dimacs = encoding.to_dimacs();
+            match &settings.path_out {
+                Some(path) => {
+                    fs::write(path, dimacs).unwrap_or_else(|err| {
+                        eprintln!("Could not write {path}: {err}");
+                        process::exit(1);
+                    });
+                    eprintln!(
+                        "Wrote {} variables and {} clauses to {path}.",
+                        formula.get_num_vars(),
+                        formula.get_clauses().len(),
+                    );
+                },
+                None => print!("{dimacs}"),
+            }
+        },
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Settings of the cnf command
+struct CnfSettings {
+    roll: Option<Vec<String>>,
+    seed: Option<String>,
+    path_out: Option<String>,
+    path_model: Option<String>,
+}
+
+impl CnfSettings {
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut settings = Self {
+            roll: None,
+            seed: None,
+            path_out: None,
+            path_model: None,
+        };
+        let mut it = args.iter();
+        while let Some(flag) = it.next() {
+            let value = it.next().ok_or_else(|| format!("Missing value for {flag}."))?;
+            match flag.as_str() {
+                "--roll" => {
+                    settings.roll = Some(value.split(',').map(|cell| cell.trim().to_string()).collect());
+                },
+                "--seed" => {
+                    settings.seed = Some(value.clone());
+                },
+                "--out" => {
+                    settings.path_out = Some(value.clone());
+                },
+                "--model" => {
+                    settings.path_model = Some(value.clone());
+                },
+                _ => {
+                    return Err(format!("Unknown option {flag}."));
+                },
+            }
+        }
+        return Ok(settings);
+    }
+}
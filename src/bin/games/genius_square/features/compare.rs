@@ -0,0 +1,129 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::process;
+use std::time::Duration;
+
+use general::_core;
+
+use general::games::genius_square::algorithms::solvers::SOLVERS;
+use general::games::genius_square::algorithms::solvers::compare_solvers;
+use general::games::genius_square::algorithms::solvers::get_solver;
+use general::games::genius_square::models::board::methods::block_from_cells;
+use general::games::genius_square::models::board::models::GameBoard;
+use general::games::genius_square::models::dice::methods::roll_dice;
+use general::games::genius_square::models::solver::models::SolveOptions;
+use general::games::genius_square::models::solver::models::Solver;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Runs several strategies (all by default) on the same roll
+/// and checks that they find the same solutions.
+///
+/// Usage:
+///
+/// ```text
+/// compare [--roll B1,C4,...] [--seed S] [--solvers NAME,...] [--timeout SECS]
+/// ```
+///
+/// Exits with an error if the strategies disagree.
+pub fn feature_compare(args: &[String]) {
+    let settings = match CompareSettings::from_args(args) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2);
+        }
+    };
+
+    // determine the roll
+    let faces = match &settings.roll {
+        Some(faces) => faces.clone(),
+        None => {
+            let seed = _core::rand::seed_from_option(settings.seed.clone());
+            println!("\nReplay token: {seed}");
+            roll_dice(&mut seed.derive("dice"))
+        },
+    };
+    let block = block_from_cells(&faces).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    let board = GameBoard::new(&block);
+    println!("\nRoll: {}.\n", faces.join(", "));
+
+    let mut options = SolveOptions::new();
+    if let Some(timeout) = settings.timeout {
+        options = options.with_timeout(timeout);
+    }
+    let comparison = compare_solvers(&board, &settings.solvers, &options);
+    let width = comparison.runs.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    for (name, outcome) in comparison.runs.iter() {
+        println!(
+            "{name:<width$}  {} solutions, search {} after {}",
+            outcome.stats.solutions,
+            outcome.status,
+            outcome.stats,
+        );
+    }
+
+    if comparison.agrees() {
+        println!("\nAll solvers agree.");
+    } else {
+        println!("\nThe solvers disagree:");
+        for disagreement in comparison.disagreements.iter() {
+            println!("- {disagreement}");
+        }
+        process::exit(1);
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Settings of the compare command
+struct CompareSettings {
+    roll: Option<Vec<String>>,
+    seed: Option<String>,
+    solvers: Vec<&'static dyn Solver>,
+    timeout: Option<Duration>,
+}
+
+impl CompareSettings {
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut settings = Self {
+            roll: None,
+            seed: None,
+            solvers: SOLVERS.to_vec(),
+            timeout: None,
+        };
+        let mut it = args.iter();
+        while let Some(flag) = it.next() {
+            let value = it.next().ok_or_else(|| format!("Missing value for {flag}."))?;
+            match flag.as_str() {
+                "--roll" => {
+                    settings.roll = Some(value.split(',').map(|cell| cell.trim().to_string()).collect());
+                },
+                "--seed" => {
+                    settings.seed = Some(value.clone());
+                },
+                "--solvers" => {
+                    settings.solvers = value.split(',').map(get_solver).collect::<Result<_, _>>()?;
+                },
+                "--timeout" => {
+                    let secs: f64 = value.parse().map_err(|_| format!("Invalid timeout {value}."))?;
+                    let timeout = Duration::try_from_secs_f64(secs).map_err(|_| format!("Invalid timeout {value}."))?;
+                    settings.timeout = Some(timeout);
+                },
+                _ => {
+                    return Err(format!("Unknown option {flag}."));
+                },
+            }
+        }
+        return Ok(settings);
+    }
+}
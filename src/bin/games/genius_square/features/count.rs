@@ -0,0 +1,161 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::fs;
+use std::process;
+
+use indicatif::ProgressBar;
+use indicatif::ProgressStyle;
+
+use general::_core;
+
+use general::games::genius_square::algorithms::count::count_memoised_with_options;
+use general::games::genius_square::models::board::methods::block_from_cells;
+use general::games::genius_square::models::board::models::GameBoard;
+use general::games::genius_square::models::dice::methods::all_rolls;
+use general::games::genius_square::models::dice::methods::block_from_roll;
+use general::games::genius_square::models::dice::methods::roll_dice;
+use general::games::genius_square::models::solver::models::SolveOptions;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Counts the solutions of a roll exactly,
+/// or of every distinct roll of the dice.
+///
+/// Usage:
+///
+/// ```text
+/// count [--roll B1,C4,...] [--seed S]
+/// count --all [--out FILE]
+/// ```
+///
+/// With `--all` the counts are written as CSV (`roll,count,nodes`),
+/// to stdout unless `--out` is given.
+pub fn feature_count(args: &[String]) {
+    let settings = match CountSettings::from_args(args) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2);
+        }
+    };
+
+    if settings.all {
+        count_all_rolls(&settings);
+        return;
+    }
+
+    // determine the roll
+    let faces = match &settings.roll {
+        Some(faces) => faces.clone(),
+        None => {
+            let seed = _core::rand::seed_from_option(settings.seed.clone());
+            println!("\nReplay token: {seed}");
+            roll_dice(&mut seed.derive("dice"))
+        },
+    };
+    let block = block_from_cells(&faces).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    let board = GameBoard::new(&block);
+    println!("\nRoll: {}.\n\n{}\n", faces.join(", "), board.pretty());
+
+    let outcome = count_memoised_with_options(&board, &SolveOptions::new());
+    println!(
+        "Found {} solutions ({} nodes) in {:.2?}.",
+        outcome.stats.solutions,
+        outcome.stats.nodes,
+        outcome.stats.elapsed,
+    );
+    if let Some(solution) = outcome.board {
+        println!("\nFor example:\n\n{}\n", solution.pretty());
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Settings of the count command
+struct CountSettings {
+    all: bool,
+    roll: Option<Vec<String>>,
+    seed: Option<String>,
+    path_out: Option<String>,
+}
+
+impl CountSettings {
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut settings = Self {
+            all: false,
+            roll: None,
+            seed: None,
+            path_out: None,
+        };
+        let mut it = args.iter();
+        while let Some(flag) = it.next() {
+            // NOTE: the only option without a value
+            if flag == "--all" {
+                settings.all = true;
+                continue;
+            }
+            let value = it.next().ok_or_else(|| format!("Missing value for {flag}."))?;
+            match flag.as_str() {
+                "--roll" => {
+                    settings.roll = Some(value.split(',').map(|cell| cell.trim().to_string()).collect());
+                },
+                "--seed" => {
+                    settings.seed = Some(value.clone());
+                },
+                "--out" => {
+                    settings.path_out = Some(value.clone());
+                },
+                _ => {
+                    return Err(format!("Unknown option {flag}."));
+                },
+            }
+        }
+        return Ok(settings);
+    }
+}
+
+/// Counts the solutions of every distinct roll and writes the counts as CSV
+fn count_all_rolls(settings: &CountSettings) {
+    let rolls = all_rolls();
+    let pbar = ProgressBar::new(rolls.len() as u64);
+    let style = ProgressStyle::with_template("{spinner:.white} [{elapsed_precise}] [{wide_bar:.white}] {pos}/{len} ({eta_precise})");
+    pbar.set_style(style.unwrap());
+
+    let options = SolveOptions::new();
+    let mut lines: Vec<String> = vec!["roll,count,nodes".to_string()];
+    let mut counts: Vec<u64> = vec![];
+    for faces in rolls.iter() {
+        let board = GameBoard::new(&block_from_roll(faces));
+        let outcome = count_memoised_with_options(&board, &options);
+        lines.push(format!("{},{},{}", faces.join(" "), outcome.stats.solutions, outcome.stats.nodes));
+        counts.push(outcome.stats.solutions);
+        pbar.inc(1);
+    }
+    pbar.finish_and_clear();
+
+    let csv = lines.join("\n") + "\n";
+    match &settings.path_out {
+        Some(path) => {
+            fs::write(path, csv).unwrap_or_else(|err| {
+                eprintln!("Could not write {path}: {err}");
+                process::exit(1);
+            });
+            println!("\nCounts of {} rolls saved to {path}.", rolls.len());
+        },
+        None => print!("{csv}"),
+    }
+
+    let min = counts.iter().min().copied().unwrap_or(0);
+    let max = counts.iter().max().copied().unwrap_or(0);
+    let mean = counts.iter().sum::<u64>() as f64 / counts.len().max(1) as f64;
+    eprintln!("Solutions per roll: min {min}, max {max}, mean {mean:.1}.");
+}
@@ -0,0 +1,131 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::process;
+
+use general::_core;
+
+use general::games::genius_square::algorithms::coverage::compute_coverage_with_options;
+use general::games::genius_square::models::board::methods::block_from_cells;
+use general::games::genius_square::models::board::models::GameBoard;
+use general::games::genius_square::models::constants::enums::EnumPiece;
+use general::games::genius_square::models::dice::methods::roll_dice;
+use general::games::genius_square::models::solver::models::SolveOptions;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Shows how often each piece covers each cell across all solutions of a roll,
+/// and which placements are forced.
+///
+/// Usage:
+///
+/// ```text
+/// coverage [--roll B1,C4,...] [--seed S] [--piece X] [--json]
+/// ```
+///
+/// Without `--piece` the grid shows the piece which covers each cell most often,
+/// with `--piece` how often the given piece covers each cell (in tenths of the solutions).
+pub fn feature_coverage(args: &[String]) {
+    let settings = match CoverageSettings::from_args(args) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2);
+        }
+    };
+
+    // determine the roll
+    let faces = match &settings.roll {
+        Some(faces) => faces.clone(),
+        None => {
+            let seed = _core::rand::seed_from_option(settings.seed.clone());
+            eprintln!("Replay token: {seed}");
+            roll_dice(&mut seed.derive("dice"))
+        },
+    };
+    let block = block_from_cells(&faces).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    let board = GameBoard::new(&block);
+    let coverage = compute_coverage_with_options(&board, &SolveOptions::new()).unwrap_or_else(|status| {
+        eprintln!("Search aborted ({}).", status.as_str());
+        process::exit(1);
+    });
+
+    if settings.json {
+        println!("{}", serde_json::to_string_pretty(&coverage.to_json()).unwrap());
+        return;
+    }
+
+    println!("\nRoll: {}.", faces.join(", "));
+    println!("Solutions: {}.", coverage.get_num_solutions());
+    match &settings.piece {
+        Some(kind) => {
+            println!("\nCoverage by {} (in tenths, * = always):\n\n{}\n", kind.as_str(), coverage.pretty_piece(kind));
+        },
+        None => {
+            println!("\nMost frequent piece per cell (highlighted = always):\n\n{}\n", coverage.pretty());
+        },
+    }
+    let forced = coverage.get_forced_placements();
+    if forced.is_empty() {
+        println!("No cell is covered by the same piece in every solution.");
+    } else {
+        println!("Forced placements:");
+        for (kind, cells) in forced {
+            println!("- {}: {}", kind.as_str(), cells.join(", "));
+        }
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Settings of the coverage command
+struct CoverageSettings {
+    roll: Option<Vec<String>>,
+    seed: Option<String>,
+    piece: Option<EnumPiece>,
+    json: bool,
+}
+
+impl CoverageSettings {
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut settings = Self {
+            roll: None,
+            seed: None,
+            piece: None,
+            json: false,
+        };
+        let mut it = args.iter();
+        while let Some(flag) = it.next() {
+            // NOTE: the only option without a value
+            if flag == "--json" {
+                settings.json = true;
+                continue;
+            }
+            let value = it.next().ok_or_else(|| format!("Missing value for {flag}."))?;
+            match flag.as_str() {
+                "--roll" => {
+                    settings.roll = Some(value.split(',').map(|cell| cell.trim().to_string()).collect());
+                },
+                "--seed" => {
+                    settings.seed = Some(value.clone());
+                },
+                "--piece" => {
+                    let kind = EnumPiece::from_symb(value).ok_or_else(|| format!("Unknown piece {value}."))?;
+                    settings.piece = Some(kind);
+                },
+                _ => {
+                    return Err(format!("Unknown option {flag}."));
+                },
+            }
+        }
+        return Ok(settings);
+    }
+}
@@ -0,0 +1,89 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::process;
+
+use general::games::genius_square::algorithms::dice::match_layout;
+use general::games::genius_square::models::board::methods::block_from_cells;
+use general::games::genius_square::models::board::models::GameBoard;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Determines whether the dice can produce a layout of blocked cells,
+/// every roll which does so and the probability of the layout.
+///
+/// Usage:
+///
+/// ```text
+/// dice --blocks B1,C4,...
+/// ```
+pub fn feature_dice(args: &[String]) {
+    let settings = match DiceSettings::from_args(args) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2);
+        }
+    };
+
+    let cells = settings.blocks.unwrap_or_else(|| {
+        eprintln!("Expected the blocked cells via --blocks.");
+        process::exit(2);
+    });
+    let layout = match_layout(&cells).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    let block = block_from_cells(&cells).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    println!("\nLayout:\n{}\n", GameBoard::new(&block).pretty());
+
+    if !layout.is_legal() {
+        println!("The dice cannot produce this layout.");
+        process::exit(1);
+    }
+    println!("Rolls producing this layout (faces of the dice in order):");
+    for faces in layout.assignments.iter() {
+        println!("- {}", faces.join(", "));
+    }
+    println!(
+        "\nProbability of the layout: {:.3e} (1 in {:.0}).",
+        layout.probability,
+        1. / layout.probability,
+    );
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Settings of the dice command
+struct DiceSettings {
+    blocks: Option<Vec<String>>,
+}
+
+impl DiceSettings {
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut settings = Self {
+            blocks: None,
+        };
+        let mut it = args.iter();
+        while let Some(flag) = it.next() {
+            let value = it.next().ok_or_else(|| format!("Missing value for {flag}."))?;
+            match flag.as_str() {
+                "--blocks" => {
+                    settings.blocks = Some(value.split(',').map(|cell| cell.trim().to_string()).collect());
+                },
+                _ => {
+                    return Err(format!("Unknown option {flag}."));
+                },
+            }
+        }
+        return Ok(settings);
+    }
+}
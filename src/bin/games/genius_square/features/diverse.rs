@@ -0,0 +1,130 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::process;
+
+use general::_core;
+
+use general::games::genius_square::algorithms::diverse::find_diverse_with_options;
+use general::games::genius_square::algorithms::diverse::solution_distance;
+use general::games::genius_square::models::board::methods::block_from_cells;
+use general::games::genius_square::models::board::methods::board_to_notation;
+use general::games::genius_square::models::board::models::GameBoard;
+use general::games::genius_square::models::dice::methods::roll_dice;
+use general::games::genius_square::models::solver::models::SolveOptions;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+const DEFAULT_NUM_SOLUTIONS: usize = 3;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Shows solutions of a roll which differ from each other as much as possible,
+/// next to each other.
+///
+/// Usage:
+///
+/// ```text
+/// diverse [--roll B1,C4,...] [--seed S] [-k N]
+/// ```
+pub fn feature_diverse(args: &[String]) {
+    let settings = match DiverseSettings::from_args(args) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2);
+        }
+    };
+
+    // determine the roll
+    let faces = match &settings.roll {
+        Some(faces) => faces.clone(),
+        None => {
+            let seed = _core::rand::seed_from_option(settings.seed.clone());
+            println!("\nReplay token: {seed}");
+            roll_dice(&mut seed.derive("dice"))
+        },
+    };
+    let block = block_from_cells(&faces).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    let board = GameBoard::new(&block);
+    println!("\nRoll: {}.", faces.join(", "));
+
+    let solutions = find_diverse_with_options(&board, &SolveOptions::new(), settings.num_solutions).unwrap_or_else(|status| {
+        eprintln!("Search aborted ({}).", status.as_str());
+        process::exit(1);
+    });
+    if solutions.is_empty() {
+        println!("The roll has no solutions.");
+        return;
+    }
+
+    // show at most as many boards in a row as fit into a wide terminal
+    for (k, chunk) in solutions.chunks(3).enumerate() {
+        let labels = (0..chunk.len())
+            .map(|l| format!("{:<29}", format!("Solution {}:", 3 * k + l + 1)))
+            .collect::<Vec<String>>()
+            .join("   ");
+        println!("\n{}\n{}", labels.trim_end(), GameBoard::pretty_side_by_side(chunk));
+    }
+    println!();
+    for (k, solution) in solutions.iter().enumerate() {
+        println!("{}. {}", k + 1, board_to_notation(solution));
+    }
+
+    println!("\nNumber of cells covered by different pieces:\n");
+    for (k, solution) in solutions.iter().enumerate() {
+        let row = solutions
+            .iter()
+            .map(|other| format!("{:>3}", solution_distance(solution, other)))
+            .collect::<String>();
+        println!("{:>3}.{row}", k + 1);
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Settings of the diverse command
+struct DiverseSettings {
+    roll: Option<Vec<String>>,
+    seed: Option<String>,
+    num_solutions: usize,
+}
+
+impl DiverseSettings {
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut settings = Self {
+            roll: None,
+            seed: None,
+            num_solutions: DEFAULT_NUM_SOLUTIONS,
+        };
+        let mut it = args.iter();
+        while let Some(flag) = it.next() {
+            let value = it.next().ok_or_else(|| format!("Missing value for {flag}."))?;
+            match flag.as_str() {
+                "--roll" => {
+                    settings.roll = Some(value.split(',').map(|cell| cell.trim().to_string()).collect());
+                },
+                "--seed" => {
+                    settings.seed = Some(value.clone());
+                },
+                "-k" => {
+                    settings.num_solutions = value.parse().map_err(|_| format!("Invalid number of solutions {value}."))?;
+                },
+                _ => {
+                    return Err(format!("Unknown option {flag}."));
+                },
+            }
+        }
+        return Ok(settings);
+    }
+}
@@ -1,3 +1,18 @@
 /// Highest logic of application.
 
+pub mod analyse;
+pub mod bench;
+pub mod cnf;
+pub mod compare;
+pub mod count;
+pub mod coverage;
+pub mod dice;
+pub mod diverse;
+pub mod pieces;
+pub mod race;
+pub mod replay;
+pub mod sample;
+pub mod serve;
 pub mod setup_game;
+pub mod solvers;
+pub mod tree;
@@ -0,0 +1,60 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use general::games::genius_square::models::pieces::catalogue::CATALOGUE;
+use general::games::genius_square::models::pieces::catalogue::Orientation;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Lists the pieces with their shapes and all distinct orientations,
+/// labelled by their canonical ids and the symmetry leading to them.
+///
+/// Usage:
+///
+/// ```text
+/// pieces
+/// ```
+pub fn feature_pieces(_args: &[String]) {
+    for info in CATALOGUE.iter() {
+        println!(
+            "\n{} {} ({}, {} cells, symmetry order {}, orientations: {}):\n",
+            info.paint(info.kind.as_str()),
+            info.name,
+            info.colour,
+            info.num_cells,
+            info.symmetry_order,
+            info.orientations.len(),
+        );
+        let blocks: Vec<Vec<String>> = info.orientations.iter().map(render).collect();
+        let height = blocks.iter().map(|lines| lines.len()).max().unwrap_or(0);
+        for i in 0..height {
+            let line = blocks
+                .iter()
+                .map(|lines| format!("{:<8}", lines.get(i).map_or("", |line| line.as_str())))
+                .collect::<String>();
+            println!("  {}", info.paint(line.trim_end()));
+        }
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+/// Renders an orientation below its label (e.g. `#1 r1:h`)
+fn render(orientation: &Orientation) -> Vec<String> {
+    let coords = orientation.positions.to_coords();
+    let m = coords.iter().map(|&(i, _)| i + 1).max().unwrap_or(0);
+    let n = coords.iter().map(|&(_, j)| j + 1).max().unwrap_or(0);
+    let mut lines = vec![format!("#{} {}", orientation.id, orientation.element)];
+    for i in 0..m {
+        let line: String = (0..n)
+            .map(|j| if coords.contains(&(i, j)) { '+' } else { '.' })
+            .collect();
+        lines.push(line);
+    }
+    return lines;
+}
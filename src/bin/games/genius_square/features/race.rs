@@ -0,0 +1,199 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::io;
+use std::io::BufRead;
+use std::process;
+use std::time::Duration;
+use std::time::Instant;
+
+use general::_core;
+
+use general::games::genius_square::algorithms::validate::validate_solution;
+use general::games::genius_square::models::board::methods::board_from_grid;
+use general::games::genius_square::models::board::methods::board_from_text;
+use general::games::genius_square::models::board::models::GameBoard;
+use general::games::genius_square::models::constants::board::GRID_HEIGHT;
+use general::games::genius_square::models::dice::methods::block_from_roll;
+use general::games::genius_square::models::dice::methods::roll_dice;
+use general::games::genius_square::models::pieces::models::Piece;
+use general::games::genius_square::models::race::models::MAX_PLAYERS;
+use general::games::genius_square::models::race::models::MIN_PLAYERS;
+use general::games::genius_square::models::race::models::Scoreboard;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+const DEFAULT_NUM_ROUNDS: usize = 3;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Local hot-seat competition between 2 to 4 players.
+///
+/// Usage:
+///
+/// ```text
+/// race --players NAME,NAME[,...] [--rounds N] [--seed S]
+/// ```
+pub fn feature_race(args: &[String]) {
+    let settings = match RaceSettings::from_args(args) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2);
+        }
+    };
+    let stdin = io::stdin();
+    let mut input = stdin.lock();
+    run_race(&mut input, &settings);
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+fn run_race<R: BufRead>(input: &mut R, settings: &RaceSettings) {
+    let seed = _core::rand::seed_from_option(settings.seed.clone());
+    let mut rng = seed.derive("race");
+    let mut scoreboard = Scoreboard::new(&settings.players);
+    println!("\nReplay token: {seed}");
+
+    for round in 1..=settings.num_rounds {
+        let faces = roll_dice(&mut rng);
+        let block = block_from_roll(&faces);
+        println!("\nRound {round}/{}.", settings.num_rounds);
+
+        // NOTE: the puzzle is only shown once the timer runs, and the screen is cleared after each turn,
+        // so that no player can study the puzzle or the solutions of others for free
+        let mut times: Vec<Option<Duration>> = vec![];
+        for name in settings.players.iter() {
+            println!("\n{name}: press enter to start the timer.");
+            if read_line(input).is_none() {
+                println!("\nInput closed, race aborted.");
+                return;
+            }
+            let time = play_turn(input, &faces, &block);
+            clear_screen();
+            println!("Round {round}/{}.\n", settings.num_rounds);
+            match time {
+                Some(t) => println!("{name} solved the puzzle in {:.1}s.", t.as_secs_f64()),
+                None => println!("{name} passed."),
+            }
+            times.push(time);
+        }
+
+        match scoreboard.record_round(&times) {
+            Some(k) => println!("\n{} wins round {round}!", settings.players[k]),
+            None => println!("\nNobody solved round {round}."),
+        }
+        println!("\n{scoreboard}");
+    }
+
+    if let Some(player) = scoreboard.ranking().first().filter(|player| player.wins > 0) {
+        println!("\n{} wins the race after {} rounds!\n", player.name, scoreboard.get_rounds());
+    }
+}
+
+/// Shows the puzzle and lets the player enter solutions until one is valid or they pass.
+/// Returns the time taken, if solved.
+fn play_turn<R: BufRead>(input: &mut R, faces: &[String], block: &Piece) -> Option<Duration> {
+    let t0 = Instant::now();
+    println!("\nRoll: {}.\n", faces.join(", "));
+    println!("{}", GameBoard::new(block).pretty());
+    loop {
+        println!("Enter your solution as a grid ({GRID_HEIGHT} lines), as placements (e.g. 1=A1; 2=C1,D1; ...) or in notation (e.g. 1:A1; 2:C1:r1; ...), or type pass:");
+        let line = loop {
+            let line = read_line(input)?;
+            if !line.trim().is_empty() {
+                break line;
+            }
+        };
+        if line.trim().eq_ignore_ascii_case("pass") {
+            return None;
+        }
+
+        let board = if line.contains('=') || line.contains(':') {
+            board_from_text(&line, block)
+        } else {
+            let mut lines = vec![line];
+            for _ in 1..GRID_HEIGHT {
+                lines.push(read_line(input)?);
+            }
+            board_from_grid(&lines.join("\n"), block)
+        };
+
+        match board.map_err(|err| vec![err]).and_then(|board| validate_solution(&board)) {
+            Ok(()) => {
+                return Some(t0.elapsed());
+            },
+            Err(errors) => {
+                println!("Not a valid solution:");
+                for err in errors {
+                    println!("- {err}");
+                }
+            },
+        }
+    }
+}
+
+/// Clears the terminal (including the scrollback) and moves the cursor to the top left.
+fn clear_screen() {
+    print!("\x1b[2J\x1b[3J\x1b[H");
+}
+
+/// Reads a line from the input, or `None` if the input is closed.
+fn read_line<R: BufRead>(input: &mut R) -> Option<String> {
+    let mut line = String::new();
+    match input.read_line(&mut line) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(line.trim_end().to_string()),
+    }
+}
+
+/// Settings of the race command
+struct RaceSettings {
+    players: Vec<String>,
+    num_rounds: usize,
+    seed: Option<String>,
+}
+
+impl RaceSettings {
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut settings = Self {
+            players: vec![],
+            num_rounds: DEFAULT_NUM_ROUNDS,
+            seed: None,
+        };
+        let mut it = args.iter();
+        while let Some(flag) = it.next() {
+            let value = it.next().ok_or_else(|| format!("Missing value for {flag}."))?;
+            match flag.as_str() {
+                "--players" => {
+                    settings.players = value
+                        .split(',')
+                        .map(|name| name.trim().to_string())
+                        .filter(|name| !name.is_empty())
+                        .collect();
+                },
+                "--rounds" => {
+                    settings.num_rounds = value.parse().map_err(|_| format!("Invalid number of rounds {value}."))?;
+                },
+                "--seed" => {
+                    settings.seed = Some(value.clone());
+                },
+                _ => {
+                    return Err(format!("Unknown option {flag}."));
+                },
+            }
+        }
+        let n = settings.players.len();
+        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&n) {
+            return Err(format!("A race needs {MIN_PLAYERS} to {MAX_PLAYERS} players (--players NAME,NAME,...), got {n}."));
+        }
+        return Ok(settings);
+    }
+}
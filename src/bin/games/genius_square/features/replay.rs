@@ -0,0 +1,96 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::fs;
+use std::process;
+
+use general::games::genius_square::algorithms::validate::validate_solution;
+use general::games::genius_square::models::record::models::GameRecord;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Replays a record of a game (see [`GameRecord`]) move by move.
+///
+/// Usage:
+///
+/// ```text
+/// replay --file FILE
+/// ```
+pub fn feature_replay(args: &[String]) {
+    let settings = match ReplaySettings::from_args(args) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2);
+        }
+    };
+
+    let path = settings.path.unwrap_or_else(|| {
+        eprintln!("Expected the record via --file.");
+        process::exit(2);
+    });
+    let text = fs::read_to_string(&path).unwrap_or_else(|err| {
+        eprintln!("Could not read {path}: {err}");
+        process::exit(1);
+    });
+    let record: GameRecord = text.parse().unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    let boards = record.replay().unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(1);
+    });
+
+    println!("\nRoll: {}.", record.roll.join(", "));
+    if let Some(seed) = &record.seed {
+        println!("Seed: {seed}");
+    }
+    println!("\nProblem:\n{}", boards[0].pretty());
+    for (step, board) in record.moves.iter().zip(boards.iter().skip(1)) {
+        println!("\n{:.1}s: {}\n{}", step.time.as_secs_f64(), step.placement, board.pretty());
+    }
+
+    match validate_solution(boards.last().unwrap()) {
+        Ok(()) => println!("\nThe game ends with a valid solution."),
+        Err(errors) => {
+            println!("\nThe game ends unsolved:");
+            for err in errors {
+                println!("- {err}");
+            }
+        },
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Settings of the replay command
+struct ReplaySettings {
+    path: Option<String>,
+}
+
+impl ReplaySettings {
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut settings = Self {
+            path: None,
+        };
+        let mut it = args.iter();
+        while let Some(flag) = it.next() {
+            let value = it.next().ok_or_else(|| format!("Missing value for {flag}."))?;
+            match flag.as_str() {
+                "--file" => {
+                    settings.path = Some(value.clone());
+                },
+                _ => {
+                    return Err(format!("Unknown option {flag}."));
+                },
+            }
+        }
+        return Ok(settings);
+    }
+}
@@ -0,0 +1,104 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::process;
+
+use general::_core;
+
+use general::games::genius_square::algorithms::count::sample_many_memoised_with_options;
+use general::games::genius_square::models::board::methods::block_from_cells;
+use general::games::genius_square::models::board::methods::board_to_notation;
+use general::games::genius_square::models::board::models::GameBoard;
+use general::games::genius_square::models::dice::methods::roll_dice;
+use general::games::genius_square::models::solver::models::SolveOptions;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+const DEFAULT_NUM_SAMPLES: usize = 1;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Shows solutions of a roll chosen uniformly at random from all of its solutions.
+///
+/// Usage:
+///
+/// ```text
+/// sample [--roll B1,C4,...] [--seed S] [--samples N]
+/// ```
+///
+/// The seed determines the roll (unless given) and the samples.
+pub fn feature_sample(args: &[String]) {
+    let settings = match SampleSettings::from_args(args) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2);
+        }
+    };
+
+    let seed = _core::rand::seed_from_option(settings.seed.clone());
+    println!("\nReplay token: {seed}");
+    let faces = settings.roll.clone().unwrap_or_else(|| roll_dice(&mut seed.derive("dice")));
+    let block = block_from_cells(&faces).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    let board = GameBoard::new(&block);
+    println!("\nRoll: {}.\n\n{}\n", faces.join(", "), board.pretty());
+
+    let mut rng = seed.derive("sample");
+    let (outcome, samples) = sample_many_memoised_with_options(&board, &SolveOptions::new(), &mut rng, settings.num_samples);
+    if samples.is_empty() {
+        println!("The roll has no solutions.");
+        return;
+    }
+    println!("Sampled from {} solutions:", outcome.stats.solutions);
+    for (k, solution) in samples.iter().enumerate() {
+        println!("\nSample {}:\n{}\nNotation: {}", k + 1, solution.pretty(), board_to_notation(solution));
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Settings of the sample command
+struct SampleSettings {
+    roll: Option<Vec<String>>,
+    seed: Option<String>,
+    num_samples: usize,
+}
+
+impl SampleSettings {
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut settings = Self {
+            roll: None,
+            seed: None,
+            num_samples: DEFAULT_NUM_SAMPLES,
+        };
+        let mut it = args.iter();
+        while let Some(flag) = it.next() {
+            let value = it.next().ok_or_else(|| format!("Missing value for {flag}."))?;
+            match flag.as_str() {
+                "--roll" => {
+                    settings.roll = Some(value.split(',').map(|cell| cell.trim().to_string()).collect());
+                },
+                "--seed" => {
+                    settings.seed = Some(value.clone());
+                },
+                "--samples" => {
+                    settings.num_samples = value.parse().map_err(|_| format!("Invalid number of samples {value}."))?;
+                },
+                _ => {
+                    return Err(format!("Unknown option {flag}."));
+                },
+            }
+        }
+        return Ok(settings);
+    }
+}
@@ -0,0 +1,270 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use serde::Deserialize;
+use serde_json::Value;
+use serde_json::json;
+use std::net::TcpListener;
+use std::process;
+use std::time::Duration;
+
+use general::_core;
+use general::app::http::HttpRequest;
+use general::app::http::HttpResponse;
+use general::app::http::HttpServerOptions;
+use general::app::http::serve;
+use general::games::genius_square::algorithms::count::count_memoised_with_options;
+use general::games::genius_square::algorithms::count::sample_memoised_with_options;
+use general::games::genius_square::algorithms::propagate::deduce;
+use general::games::genius_square::algorithms::solvers::DEFAULT_SOLVER;
+use general::games::genius_square::algorithms::solvers::get_solver;
+use general::games::genius_square::algorithms::validate::validate_solution;
+use general::games::genius_square::models::board::methods::block_from_cells;
+use general::games::genius_square::models::board::methods::board_from_text;
+use general::games::genius_square::models::board::methods::board_to_notation;
+use general::games::genius_square::models::board::models::GameBoard;
+use general::games::genius_square::models::constants::dice::NUM_DICE;
+use general::games::genius_square::models::dice::methods::roll_dice;
+use general::games::genius_square::models::pieces::models::Piece;
+use general::games::genius_square::models::solver::models::SolveOptions;
+use general::games::genius_square::models::solver::models::SolveOutcome;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+const DEFAULT_HOST: &str = "127.0.0.1";
+const DEFAULT_PORT: u16 = 8080;
+/// Default time limit for the solver (in milliseconds)
+const DEFAULT_SOLVER_TIMEOUT_MS: u64 = 10_000;
+/// Maximal time limit for the solver (in milliseconds), which requests cannot exceed
+const MAX_SOLVER_TIMEOUT_MS: u64 = 60_000;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Starts a local HTTP server offering a JSON API:
+///
+/// - `GET /roll?seed=S` rolls the dice,
+/// - `POST /solve` with body `{"roll": [...]}` or `{"blocks": [...]}` (and optionally `"solver"`) solves a problem,
+/// - `POST /validate` with body `{"roll": [...], "solution": "..."}` checks a solution,
+/// - `GET /count?roll=B1,C4,...` counts all solutions,
+/// - `GET /sample?roll=B1,C4,...&seed=S` picks a solution uniformly at random.
+///
+/// The solver endpoints accept the optional limits `timeout_ms` (at most one minute) and `max_nodes`.
+/// Rolls and blocks consist of exactly 7 distinct cells.
+///
+/// Usage:
+///
+/// ```text
+/// serve [--host HOST] [--port PORT] [--max-connections N] [--timeout SECS]
+/// ```
+pub fn feature_serve(args: &[String]) {
+    let (address, options) = match parse_args(args) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2);
+        }
+    };
+    let listener = TcpListener::bind(&address).unwrap_or_else(|err| {
+        eprintln!("Could not listen on {address}: {err}");
+        process::exit(1);
+    });
+    println!("\nListening on http://{address} (press Ctrl+C to stop).");
+    serve(listener, &options, handle_request);
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+fn handle_request(request: &HttpRequest) -> HttpResponse {
+    let result = match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/roll") => handle_roll(request),
+        ("POST", "/solve") => handle_solve(request),
+        ("POST", "/validate") => handle_validate(request),
+        ("GET", "/count") => handle_count(request),
+        ("GET", "/sample") => handle_sample(request),
+        (_, "/roll" | "/solve" | "/validate" | "/count" | "/sample") => Err((405, "Method not allowed.".to_string())),
+        _ => Err((404, format!("Unknown endpoint {}.", request.path))),
+    };
+    match result {
+        Ok(body) => HttpResponse::json(200, &body.to_string()),
+        Err((status, message)) => HttpResponse::error(status, &message),
+    }
+}
+
+fn handle_roll(request: &HttpRequest) -> Result<Value, (u16, String)> {
+    let seed = _core::rand::seed_from_option(request.query.get("seed").cloned());
+    let faces = roll_dice(&mut seed.derive("dice"));
+    return Ok(json!({
+        "seed": seed.to_token(),
+        "roll": faces,
+    }));
+}
+
+fn handle_solve(request: &HttpRequest) -> Result<Value, (u16, String)> {
+    let body: SolveBody = parse_body(request)?;
+    let cells = body.roll.or(body.blocks).ok_or((422, "Expected either roll or blocks.".to_string()))?;
+    let block = parse_block(&cells)?;
+    let solver = get_solver(body.solver.as_deref().unwrap_or(DEFAULT_SOLVER)).map_err(|err| (422, err))?;
+    let options = solver_options(body.timeout_ms, body.max_nodes);
+    let board = GameBoard::new(&block);
+    let outcome = solver.solve(&board, &options);
+    let mut result = outcome_to_json(&outcome);
+    let deductions: Vec<String> = match deduce(&board) {
+        Ok((_, deductions)) => deductions.iter().map(|deduction| deduction.to_string()).collect(),
+        Err(contradiction) => vec![contradiction.to_string()],
+    };
+    result["deductions"] = json!(deductions);
+    return Ok(result);
+}
+
+fn handle_validate(request: &HttpRequest) -> Result<Value, (u16, String)> {
+    let body: ValidateBody = parse_body(request)?;
+    let block = parse_block(&body.roll)?;
+    let errors = match board_from_text(&body.solution, &block) {
+        Ok(board) => validate_solution(&board).err().unwrap_or_default(),
+        Err(err) => vec![err],
+    };
+    return Ok(json!({
+        "valid": errors.is_empty(),
+        "errors": errors,
+    }));
+}
+
+fn handle_count(request: &HttpRequest) -> Result<Value, (u16, String)> {
+    let (board, options) = parse_query(request)?;
+    let outcome = count_memoised_with_options(&board, &options);
+    return Ok(json!({
+        "status": outcome.status.as_str(),
+        "complete": outcome.status.is_finished(),
+        "count": outcome.stats.solutions,
+        "nodes": outcome.stats.nodes,
+        "elapsed_ms": outcome.stats.elapsed.as_millis() as u64,
+    }));
+}
+
+fn handle_sample(request: &HttpRequest) -> Result<Value, (u16, String)> {
+    let (board, options) = parse_query(request)?;
+    let seed = _core::rand::seed_from_option(request.query.get("seed").cloned());
+    let outcome = sample_memoised_with_options(&board, &options, &mut seed.derive("sample"));
+    let mut body = outcome_to_json(&outcome);
+    body["seed"] = json!(seed.to_token());
+    body["count"] = json!(outcome.stats.solutions);
+    return Ok(body);
+}
+
+/// Parses the roll and the limits of the solver from the query
+fn parse_query(request: &HttpRequest) -> Result<(GameBoard, SolveOptions), (u16, String)> {
+    let roll = request.query.get("roll").ok_or((422, "Expected query parameter roll.".to_string()))?;
+    let cells: Vec<String> = roll.split(',').map(|cell| cell.to_string()).collect();
+    let block = parse_block(&cells)?;
+    let parse = |key: &str| -> Result<Option<u64>, (u16, String)> {
+        request.query
+            .get(key)
+            .map(|value| value.parse::<u64>().map_err(|_| (422, format!("Invalid {key} {value}."))))
+            .transpose()
+    };
+    let options = solver_options(parse("timeout_ms")?, parse("max_nodes")?);
+    return Ok((GameBoard::new(&block), options));
+}
+
+/// Creates the blocking piece from the cells of a roll (one per die)
+fn parse_block(cells: &[String]) -> Result<Piece, (u16, String)> {
+    let block = block_from_cells(cells).map_err(|err| (422, err))?;
+    if cells.len() != NUM_DICE || block.to_coords().len() != NUM_DICE {
+        return Err((422, format!("Expected {NUM_DICE} distinct cells.")));
+    }
+    return Ok(block);
+}
+
+/// Limits of the solver (the time limit is capped, as every request occupies a connection)
+fn solver_options(timeout_ms: Option<u64>, max_nodes: Option<u64>) -> SolveOptions {
+    let timeout_ms = timeout_ms.unwrap_or(DEFAULT_SOLVER_TIMEOUT_MS).min(MAX_SOLVER_TIMEOUT_MS);
+    let mut options = SolveOptions::new().with_timeout(Duration::from_millis(timeout_ms));
+    if let Some(max_nodes) = max_nodes {
+        options = options.with_max_nodes(max_nodes);
+    }
+    return options;
+}
+
+fn outcome_to_json(outcome: &SolveOutcome) -> Value {
+    let solution = outcome.board.as_ref().map(|board| {
+        json!({
+            "grid": board.to_rows(),
+            "placements": board
+                .to_placements()
+                .into_iter()
+                .map(|(kind, cells)| (kind.as_str().to_string(), json!(cells)))
+                .collect::<serde_json::Map<String, Value>>(),
+            "notation": board_to_notation(board),
+        })
+    });
+    return json!({
+        "status": outcome.status.as_str(),
+        "solution": solution,
+        "nodes": outcome.stats.nodes,
+        "elapsed_ms": outcome.stats.elapsed.as_millis() as u64,
+    });
+}
+
+fn parse_body<T: for<'de> Deserialize<'de>>(request: &HttpRequest) -> Result<T, (u16, String)> {
+    let text = request.body_as_str().map_err(|err| (400, err))?;
+    return serde_json::from_str(text).map_err(|err| (400, format!("Invalid JSON body: {err}")));
+}
+
+fn parse_args(args: &[String]) -> Result<(String, HttpServerOptions), String> {
+    let mut host = DEFAULT_HOST.to_string();
+    let mut port = DEFAULT_PORT;
+    let mut options = HttpServerOptions::default();
+    let mut it = args.iter();
+    while let Some(flag) = it.next() {
+        let value = it.next().ok_or_else(|| format!("Missing value for {flag}."))?;
+        match flag.as_str() {
+            "--host" => {
+                host = value.clone();
+            },
+            "--port" => {
+                port = value.parse().map_err(|_| format!("Invalid port {value}."))?;
+            },
+            "--max-connections" => {
+                options.max_connections = value.parse().map_err(|_| format!("Invalid number of connections {value}."))?;
+            },
+            "--timeout" => {
+                let secs: f64 = value.parse().map_err(|_| format!("Invalid timeout {value}."))?;
+                let timeout = Duration::try_from_secs_f64(secs).map_err(|_| format!("Invalid timeout {value}."))?;
+                options.timeout = timeout;
+            },
+            _ => {
+                return Err(format!("Unknown option {flag}."));
+            },
+        }
+    }
+    return Ok((format!("{host}:{port}"), options));
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Body of `POST /solve`
+#[derive(Deserialize)]
+struct SolveBody {
+    roll: Option<Vec<String>>,
+    blocks: Option<Vec<String>>,
+    /// name of the strategy (see `SOLVERS`)
+    solver: Option<String>,
+    timeout_ms: Option<u64>,
+    max_nodes: Option<u64>,
+}
+
+/// Body of `POST /validate`
+#[derive(Deserialize)]
+struct ValidateBody {
+    roll: Vec<String>,
+    solution: String,
+}
@@ -4,12 +4,14 @@
 
 use rand_chacha::ChaCha8Rng;
 
-use crate::models::dice::methods::roll_dice;
-use crate::models::dice::models::Die;
-use crate::models::constants::enums::EnumPiece;
-use crate::models::pieces::models::Piece;
-use crate::models::board::models::GameBoard;
-use crate::algorithms::solve::solve_brute_force;
+use general::games::genius_square::algorithms::dice::match_layout;
+use general::games::genius_square::models::dice::methods::block_from_roll;
+use general::games::genius_square::models::dice::methods::roll_dice;
+use general::games::genius_square::models::board::methods::board_to_notation;
+use general::games::genius_square::models::board::models::GameBoard;
+use general::games::genius_square::algorithms::propagate::deduce;
+use general::games::genius_square::models::solver::models::SolveOptions;
+use general::games::genius_square::models::solver::models::Solver;
 
 /// ----------------------------------------------------------------
 /// METHODS
@@ -18,22 +20,44 @@ use crate::algorithms::solve::solve_brute_force;
 pub fn feature_setup_game(
     rng: &mut ChaCha8Rng,
     option_roll: Option<Vec<String>>,
+    solver: &dyn Solver,
 ) {
     // Roll the dice
     let faces = option_roll.unwrap_or_else(|| roll_dice(rng));
-    let dice: Vec<Die> = faces.iter()
-        .map(|face| Die::from_string(face))
-        .collect();
     println!("\nRoll: {}.\n", faces.join(", "));
+    if match_layout(&faces).is_ok_and(|layout| !layout.is_legal()) {
+        println!("NOTE: the dice cannot produce this layout.\n");
+    }
 
     // Establish the problem
-    let coords = dice.iter().map(|die| die.to_coords()).collect();
-    let block = Piece::from_coords(coords, Some(EnumPiece::Block));
+    let block = block_from_roll(&faces);
     let mut board = GameBoard::new(&block);
     println!("\nProblem:\n{}", board.pretty());
 
+    // Show what follows logically before any guessing
+    match deduce(&board) {
+        Ok((_, deductions)) if deductions.is_empty() => {
+            println!("\nNo placement is forced at the start.");
+        },
+        Ok((_, deductions)) => {
+            println!("\nLogical deductions:");
+            for (k, deduction) in deductions.iter().enumerate() {
+                println!("{}. {deduction}", k + 1);
+            }
+        },
+        Err(contradiction) => {
+            println!("\nThe problem has no solution: {contradiction}");
+        },
+    }
+
     // Solve the problem
-    println!("\nCompute solution...\n");
-    board = solve_brute_force(&board);
+    println!("\nCompute solution ({})...\n", solver.name());
+    let options = SolveOptions::new().with_progress(true);
+    let outcome = solver.solve(&board, &options);
+    println!("...search {} after {} nodes.", outcome.status, outcome.stats.nodes);
+    if let Some(board_) = outcome.board {
+        board = board_;
+    }
     println!("\nSolution:\n{}\n", board.pretty());
+    println!("Notation: {}\n", board_to_notation(&board));
 }
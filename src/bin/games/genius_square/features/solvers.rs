@@ -0,0 +1,26 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use general::games::genius_square::algorithms::solvers::DEFAULT_SOLVER;
+use general::games::genius_square::algorithms::solvers::SOLVERS;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Lists the strategies which can be chosen via `--solver NAME`.
+///
+/// Usage:
+///
+/// ```text
+/// solvers
+/// ```
+pub fn feature_solvers(_args: &[String]) {
+    println!("\nAvailable solvers:\n");
+    let width = SOLVERS.iter().map(|solver| solver.name().len()).max().unwrap_or(0);
+    for solver in SOLVERS.iter() {
+        let note = if solver.name() == DEFAULT_SOLVER { " (default)" } else { "" };
+        println!("- {:<width$}  {}{note}", solver.name(), solver.description());
+    }
+}
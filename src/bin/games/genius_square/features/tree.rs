@@ -0,0 +1,144 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::process;
+use std::time::Duration;
+
+use general::_core;
+
+use general::games::genius_square::algorithms::solvers::DEFAULT_SOLVER;
+use general::games::genius_square::algorithms::solvers::get_solver;
+use general::games::genius_square::models::board::methods::block_from_cells;
+use general::games::genius_square::models::board::methods::board_from_text;
+use general::games::genius_square::models::board::models::GameBoard;
+use general::games::genius_square::models::dice::methods::roll_dice;
+use general::games::genius_square::models::solver::models::SolveOptions;
+use general::games::genius_square::models::solver::models::Solver;
+use general::models::tree::base::GenericTreeLike;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+const DEFAULT_DEPTH: usize = 3;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Shows the search tree explored by a solver on a roll,
+/// optionally starting from a board with some pieces already placed.
+///
+/// Usage:
+///
+/// ```text
+/// tree [--roll B1,C4,...] [--seed S] [--board PIECES] [--solver NAME] [--depth N|all] [--timeout SECS]
+/// ```
+///
+/// The pieces are given in any notation of boards, e.g. `X:B2; L:A2:r2:h`.
+/// Only the strategies `brute-force` and `propagate` (the default) record search trees.
+pub fn feature_tree(args: &[String]) {
+    let settings = match TreeSettings::from_args(args) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2);
+        }
+    };
+
+    // determine the roll
+    let faces = match &settings.roll {
+        Some(faces) => faces.clone(),
+        None => {
+            let seed = _core::rand::seed_from_option(settings.seed.clone());
+            println!("\nReplay token: {seed}");
+            roll_dice(&mut seed.derive("dice"))
+        },
+    };
+    let block = block_from_cells(&faces).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    let board = match &settings.board {
+        Some(text) => board_from_text(text, &block).unwrap_or_else(|err| {
+            eprintln!("{err}");
+            process::exit(2);
+        }),
+        None => GameBoard::new(&block),
+    };
+    println!("\nRoll: {}.\n", faces.join(", "));
+    println!("{}\n", board.pretty());
+
+    let mut options = SolveOptions::new();
+    if let Some(timeout) = settings.timeout {
+        options = options.with_timeout(timeout);
+    }
+    let (outcome, tree) = settings.solver.solve_with_tree(&board, &options, settings.depth).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    println!("{}", tree.repr_tree(None, None, None).join("\n"));
+    println!("\nSearch {} after {}.", outcome.status, outcome.stats);
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Settings of the tree command
+struct TreeSettings {
+    roll: Option<Vec<String>>,
+    seed: Option<String>,
+    board: Option<String>,
+    solver: &'static dyn Solver,
+    /// depth up to which nodes are shown (unlimited if none)
+    depth: Option<usize>,
+    timeout: Option<Duration>,
+}
+
+impl TreeSettings {
+    fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut settings = Self {
+            roll: None,
+            seed: None,
+            board: None,
+            solver: get_solver(DEFAULT_SOLVER)?,
+            depth: Some(DEFAULT_DEPTH),
+            timeout: None,
+        };
+        let mut it = args.iter();
+        while let Some(flag) = it.next() {
+            let value = it.next().ok_or_else(|| format!("Missing value for {flag}."))?;
+            match flag.as_str() {
+                "--roll" => {
+                    settings.roll = Some(value.split(',').map(|cell| cell.trim().to_string()).collect());
+                },
+                "--seed" => {
+                    settings.seed = Some(value.clone());
+                },
+                "--board" => {
+                    settings.board = Some(value.clone());
+                },
+                "--solver" => {
+                    settings.solver = get_solver(value)?;
+                },
+                "--depth" => {
+                    settings.depth = match value.as_str() {
+                        "all" => None,
+                        _ => Some(value.parse().map_err(|_| format!("Invalid depth {value}."))?),
+                    };
+                },
+                "--timeout" => {
+                    let secs: f64 = value.parse().map_err(|_| format!("Invalid timeout {value}."))?;
+                    let timeout = Duration::try_from_secs_f64(secs).map_err(|_| format!("Invalid timeout {value}."))?;
+                    settings.timeout = Some(timeout);
+                },
+                _ => {
+                    return Err(format!("Unknown option {flag}."));
+                },
+            }
+        }
+        return Ok(settings);
+    }
+}
@@ -1,5 +0,0 @@
-/// Modules available for Genius Square binary
-
-pub mod algorithms;
-pub mod features;
-pub mod models;
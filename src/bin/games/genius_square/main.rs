@@ -3,16 +3,40 @@
 /// ----------------------------------------------------------------
 
 use std::env;
+use std::process;
 
 use general::app::messages::welcome_screen;
 use general::_core;
+use general::_core::alloc::CountingAllocator;
 
-mod algorithms;
-mod models;
 mod features;
 
-use models::constants::dice::NUM_DICE;
+use general::games::genius_square::algorithms::solvers::DEFAULT_SOLVER;
+use general::games::genius_square::algorithms::solvers::get_solver;
+use general::games::genius_square::models::constants::dice::NUM_DICE;
+use features::analyse::feature_analyse;
+use features::bench::feature_bench;
+use features::cnf::feature_cnf;
+use features::compare::feature_compare;
+use features::count::feature_count;
+use features::coverage::feature_coverage;
+use features::dice::feature_dice;
+use features::diverse::feature_diverse;
+use features::pieces::feature_pieces;
+use features::race::feature_race;
+use features::replay::feature_replay;
+use features::sample::feature_sample;
+use features::serve::feature_serve;
 use features::setup_game::feature_setup_game;
+use features::solvers::feature_solvers;
+use features::tree::feature_tree;
+
+/// ----------------------------------------------------------------
+/// GLOBALS
+/// ----------------------------------------------------------------
+
+#[global_allocator]
+static ALLOC: CountingAllocator = CountingAllocator;
 
 /// ----------------------------------------------------------------
 /// MAIN
@@ -20,9 +44,92 @@ use features::setup_game::feature_setup_game;
 
 fn main() {
     let args: Vec<String> = env::args().skip(1).collect();
-    let option_roll = if args.len() >= NUM_DICE { Some(args[0..NUM_DICE].to_vec()) } else { None };
-    let option_seed = if args.len() >= 1 { Some(args[args.len() - 1].clone()) } else { None };
-    let mut rng = _core::rand::seed_rng(option_seed);
-    welcome_screen();
-    feature_setup_game(&mut rng, option_roll);
+    match args.first().map(|arg| arg.as_str()) {
+        Some("analyse") => {
+            welcome_screen();
+            feature_analyse(&args[1..]);
+        },
+        Some("bench") => {
+            welcome_screen();
+            feature_bench(&args[1..]);
+        },
+        Some("cnf") => {
+            // NOTE: no welcome screen, so that the formula can be piped into a solver
+            feature_cnf(&args[1..]);
+        },
+        Some("compare") => {
+            welcome_screen();
+            feature_compare(&args[1..]);
+        },
+        Some("count") => {
+            // NOTE: no welcome screen, so that the counts can be piped
+            feature_count(&args[1..]);
+        },
+        Some("coverage") => {
+            // NOTE: no welcome screen, so that the json output can be piped
+            feature_coverage(&args[1..]);
+        },
+        Some("dice") => {
+            welcome_screen();
+            feature_dice(&args[1..]);
+        },
+        Some("diverse") => {
+            welcome_screen();
+            feature_diverse(&args[1..]);
+        },
+        Some("pieces") => {
+            welcome_screen();
+            feature_pieces(&args[1..]);
+        },
+        Some("race") => {
+            welcome_screen();
+            feature_race(&args[1..]);
+        },
+        Some("replay") => {
+            welcome_screen();
+            feature_replay(&args[1..]);
+        },
+        Some("sample") => {
+            welcome_screen();
+            feature_sample(&args[1..]);
+        },
+        Some("serve") => {
+            welcome_screen();
+            feature_serve(&args[1..]);
+        },
+        Some("solvers") => {
+            welcome_screen();
+            feature_solvers(&args[1..]);
+        },
+        Some("tree") => {
+            welcome_screen();
+            feature_tree(&args[1..]);
+        },
+        _ => {
+            // NOTE: the solver is the only option, the remaining arguments are positional
+            let mut args = args.clone();
+            let mut solver_name = DEFAULT_SOLVER.to_string();
+            if let Some(index) = args.iter().position(|arg| arg == "--solver") {
+                let Some(name) = args.get(index + 1).cloned() else {
+                    eprintln!("Missing value for --solver.");
+                    process::exit(2);
+                };
+                solver_name = name;
+                args.drain(index..index + 2);
+            }
+            let solver = get_solver(&solver_name).unwrap_or_else(|err| {
+                eprintln!("{err}");
+                process::exit(2);
+            });
+            let option_roll = if args.len() >= NUM_DICE { Some(args[0..NUM_DICE].to_vec()) } else { None };
+            let option_seed = if args.len() >= 1 { Some(args[args.len() - 1].clone()) } else { None };
+            let seed = _core::rand::seed_from_option(option_seed);
+            let mut rng = seed.derive("dice");
+            welcome_screen();
+            if option_roll.is_none() {
+                println!("\nReplay token: {seed}");
+            }
+            feature_setup_game(&mut rng, option_roll, solver);
+        },
+    }
 }
@@ -1,5 +0,0 @@
-/// Modules for code base Genius Square
-
-pub mod algorithms;
-pub mod features;
-pub mod models;
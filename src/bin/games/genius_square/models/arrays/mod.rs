@@ -1,3 +0,0 @@
-/// Models for handling arrays
-
-pub mod models;
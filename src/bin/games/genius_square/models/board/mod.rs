@@ -1,3 +0,0 @@
-/// Models for handling game board
-
-pub mod models;
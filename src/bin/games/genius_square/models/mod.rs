@@ -1,7 +0,0 @@
-/// Models used in game.
-
-pub mod arrays;
-pub mod board;
-pub mod constants;
-pub mod dice;
-pub mod pieces;
@@ -0,0 +1,269 @@
+/// C interface of the Genius Square engine (see the header `include/genius_square.h`).
+///
+/// All functions return a status code (see the constants `GS_*`) instead of panicking.
+/// Panics inside the engine are caught at the boundary and reported as [`GS_ERR_PANIC`].
+
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::ffi::CStr;
+use std::ffi::c_char;
+use std::panic::AssertUnwindSafe;
+use std::panic::catch_unwind;
+use std::ptr;
+use std::time::Duration;
+
+use crate::games::genius_square::algorithms::count::count_memoised_with_options;
+use crate::games::genius_square::algorithms::dice::match_layout;
+use crate::games::genius_square::algorithms::solve::solve_brute_force_with_options;
+use crate::games::genius_square::models::board::methods::block_from_cells;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::constants::board::GRID_HEIGHT;
+use crate::games::genius_square::models::constants::board::GRID_WIDTH;
+use crate::games::genius_square::models::constants::dice::NUM_DICE;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveStatus;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+pub const GS_OK: i32 = 0;
+/// A required pointer was null.
+pub const GS_ERR_NULL_POINTER: i32 = 1;
+/// The faces do not describe a roll of the dice.
+pub const GS_ERR_INVALID_FACES: i32 = 2;
+/// The search finished without finding a solution.
+pub const GS_ERR_NO_SOLUTION: i32 = 3;
+/// The search stopped at the time or node limit.
+pub const GS_ERR_LIMIT_REACHED: i32 = 4;
+/// The puzzle has not been solved yet.
+pub const GS_ERR_NOT_SOLVED: i32 = 5;
+/// The buffer provided by the caller is too small.
+pub const GS_ERR_BUFFER_TOO_SMALL: i32 = 6;
+/// The solver cannot handle the puzzle.
+pub const GS_ERR_UNSUPPORTED: i32 = 7;
+/// The engine panicked (this is a bug).
+pub const GS_ERR_PANIC: i32 = 99;
+
+/// Number of bytes needed for a solution (see [`gs_puzzle_copy_solution`])
+pub const GS_GRID_SIZE: usize = GRID_HEIGHT * GRID_WIDTH;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Opaque handle of a puzzle
+pub struct GsPuzzle {
+    board: GameBoard,
+    solution: Option<GameBoard>,
+}
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Creates a puzzle from the seven faces of a roll (e.g. `"B1"`).
+///
+/// The faces may be given in any order,
+/// but there must be a way to show them all with one face per die.
+///
+/// # Safety #
+///
+/// `faces` must point to `num_faces` null-terminated strings
+/// and `out` must point to writable memory.
+/// The puzzle written to `out` must be released with [`gs_puzzle_free`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gs_puzzle_new(
+    faces: *const *const c_char,
+    num_faces: usize,
+    out: *mut *mut GsPuzzle,
+) -> i32 {
+    guard(|| {
+        if faces.is_null() || out.is_null() {
+            return GS_ERR_NULL_POINTER;
+        }
+        if num_faces != NUM_DICE {
+            return GS_ERR_INVALID_FACES;
+        }
+        let mut cells: Vec<String> = vec![];
+        for k in 0..num_faces {
+            let face = unsafe { *faces.add(k) };
+            if face.is_null() {
+                return GS_ERR_NULL_POINTER;
+            }
+            match unsafe { CStr::from_ptr(face) }.to_str() {
+                Ok(face) => cells.push(face.trim().to_uppercase()),
+                Err(_) => return GS_ERR_INVALID_FACES,
+            }
+        }
+        // the faces may be given in any order, but each must be shown by a different die
+        match match_layout(&cells) {
+            Ok(layout) if !layout.assignments.is_empty() => {},
+            _ => return GS_ERR_INVALID_FACES,
+        }
+        let block = match block_from_cells(&cells) {
+            Ok(block) => block,
+            Err(_) => return GS_ERR_INVALID_FACES,
+        };
+        let puzzle = GsPuzzle {
+            board: GameBoard::new(&block),
+            solution: None,
+        };
+        unsafe { *out = Box::into_raw(Box::new(puzzle)) };
+        return GS_OK;
+    })
+}
+
+/// Releases a puzzle. Null pointers are ignored.
+///
+/// # Safety #
+///
+/// `puzzle` must have been created by [`gs_puzzle_new`] and not yet been released.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gs_puzzle_free(puzzle: *mut GsPuzzle) {
+    if puzzle.is_null() {
+        return;
+    }
+    let _ = catch_unwind(AssertUnwindSafe(|| drop(unsafe { Box::from_raw(puzzle) })));
+}
+
+/// Solves the puzzle and keeps the solution for [`gs_puzzle_copy_solution`].
+///
+/// The limits are ignored if they are `0`.
+///
+/// # Safety #
+///
+/// `puzzle` must be a valid puzzle (see [`gs_puzzle_new`]).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gs_puzzle_solve(
+    puzzle: *mut GsPuzzle,
+    timeout_ms: u64,
+    max_nodes: u64,
+) -> i32 {
+    guard(|| {
+        let Some(puzzle) = (unsafe { puzzle.as_mut() }) else {
+            return GS_ERR_NULL_POINTER;
+        };
+        let options = create_options(timeout_ms, max_nodes);
+        let outcome = solve_brute_force_with_options(&puzzle.board, &options);
+        puzzle.solution = outcome.board;
+        return status_to_code(&outcome.status);
+    })
+}
+
+/// Copies the solution into a buffer of at least [`GS_GRID_SIZE`] bytes,
+/// row by row, where each byte is the code of the cell contents
+/// (`0` = blank, `1` = block, `2`-`10` = pieces `1`, `2`, `3`, `4`, `C`, `L`, `T`, `X`, `Z`).
+///
+/// # Safety #
+///
+/// `puzzle` must be a valid puzzle (see [`gs_puzzle_new`])
+/// and `buffer` must point to `len` writable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gs_puzzle_copy_solution(
+    puzzle: *const GsPuzzle,
+    buffer: *mut u8,
+    len: usize,
+) -> i32 {
+    guard(|| {
+        let Some(puzzle) = (unsafe { puzzle.as_ref() }) else {
+            return GS_ERR_NULL_POINTER;
+        };
+        if buffer.is_null() {
+            return GS_ERR_NULL_POINTER;
+        }
+        let Some(solution) = &puzzle.solution else {
+            return GS_ERR_NOT_SOLVED;
+        };
+        if len < GS_GRID_SIZE {
+            return GS_ERR_BUFFER_TOO_SMALL;
+        }
+        let codes: Vec<u8> = solution.to_codes().iter().cloned().collect();
+        unsafe { ptr::copy_nonoverlapping(codes.as_ptr(), buffer, GS_GRID_SIZE) };
+        return GS_OK;
+    })
+}
+
+/// Counts all solutions of the puzzle.
+///
+/// The limits are ignored if they are `0`.
+/// If a limit is reached, `0` is written
+/// and [`GS_ERR_LIMIT_REACHED`] is returned.
+///
+/// # Safety #
+///
+/// `puzzle` must be a valid puzzle (see [`gs_puzzle_new`])
+/// and `count` must point to writable memory.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gs_puzzle_count(
+    puzzle: *const GsPuzzle,
+    timeout_ms: u64,
+    max_nodes: u64,
+    count: *mut u64,
+) -> i32 {
+    guard(|| {
+        let Some(puzzle) = (unsafe { puzzle.as_ref() }) else {
+            return GS_ERR_NULL_POINTER;
+        };
+        if count.is_null() {
+            return GS_ERR_NULL_POINTER;
+        }
+        let options = create_options(timeout_ms, max_nodes);
+        let outcome = count_memoised_with_options(&puzzle.board, &options);
+        unsafe { *count = outcome.stats.solutions };
+        return match outcome.status {
+            SolveStatus::Solved | SolveStatus::Exhausted => GS_OK,
+            status => status_to_code(&status),
+        };
+    })
+}
+
+/// Describes a status code. The string is static and must not be freed.
+#[unsafe(no_mangle)]
+pub extern "C" fn gs_status_message(code: i32) -> *const c_char {
+    let message = match code {
+        GS_OK => c"ok",
+        GS_ERR_NULL_POINTER => c"null pointer",
+        GS_ERR_INVALID_FACES => c"invalid faces",
+        GS_ERR_NO_SOLUTION => c"no solution",
+        GS_ERR_LIMIT_REACHED => c"limit reached",
+        GS_ERR_NOT_SOLVED => c"not solved",
+        GS_ERR_BUFFER_TOO_SMALL => c"buffer too small",
+        GS_ERR_UNSUPPORTED => c"unsupported",
+        GS_ERR_PANIC => c"internal error",
+        _ => c"unknown status",
+    };
+    return message.as_ptr();
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+/// Runs the body, converting panics to [`GS_ERR_PANIC`],
+/// so that unwinding never crosses the boundary.
+fn guard<F: FnOnce() -> i32>(body: F) -> i32 {
+    catch_unwind(AssertUnwindSafe(body)).unwrap_or(GS_ERR_PANIC)
+}
+
+fn create_options(timeout_ms: u64, max_nodes: u64) -> SolveOptions {
+    let mut options = SolveOptions::new();
+    if timeout_ms > 0 {
+        options = options.with_timeout(Duration::from_millis(timeout_ms));
+    }
+    if max_nodes > 0 {
+        options = options.with_max_nodes(max_nodes);
+    }
+    return options;
+}
+
+fn status_to_code(status: &SolveStatus) -> i32 {
+    match status {
+        SolveStatus::Solved => GS_OK,
+        SolveStatus::Exhausted => GS_ERR_NO_SOLUTION,
+        SolveStatus::TimedOut | SolveStatus::NodeLimit | SolveStatus::Cancelled => GS_ERR_LIMIT_REACHED,
+        SolveStatus::Unsupported => GS_ERR_UNSUPPORTED,
+    }
+}
@@ -0,0 +1,8 @@
+/// Bindings of the library for other languages.
+
+pub mod capi;
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(test)]
+pub mod tests_capi;
@@ -0,0 +1,167 @@
+/// Python extension module (build with the feature `python`, e.g. via `maturin`).
+///
+/// ```python
+/// import code_challenges_rs as rs
+///
+/// roll, token = rs.genius_square_roll("1234")
+/// result = rs.genius_square_solve(roll, timeout_ms=5000)
+/// result["codes"] # 6 x 6 numpy array, indices into rs.GENIUS_SQUARE_SYMBOLS
+/// ```
+
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use numpy::PyArray2;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::_core::rand::seed_from_option;
+use crate::games::genius_square::algorithms::count::count_memoised_with_options;
+use crate::games::genius_square::algorithms::solve::solve_brute_force_with_options;
+use crate::games::genius_square::models::board::methods::block_from_cells;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::constants::enums::ENUM_CELLS;
+use crate::games::genius_square::models::dice::methods::roll_dice;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveOutcome;
+use crate::problems::hackerrank::mathematics::scalar_products;
+use crate::problems::hackerrank::project_euler::problem10_prime_summation;
+
+/// ----------------------------------------------------------------
+/// MODULE
+/// ----------------------------------------------------------------
+
+#[pymodule]
+fn code_challenges_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("GENIUS_SQUARE_SYMBOLS", genius_square_symbols())?;
+    m.add_function(wrap_pyfunction!(genius_square_roll, m)?)?;
+    m.add_function(wrap_pyfunction!(genius_square_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(genius_square_count, m)?)?;
+    m.add_function(wrap_pyfunction!(scalar_products_run, m)?)?;
+    m.add_function(wrap_pyfunction!(prime_summation_run, m)?)?;
+    Ok(())
+}
+
+/// ----------------------------------------------------------------
+/// METHODS - GENIUS SQUARE
+/// ----------------------------------------------------------------
+
+/// Rolls the dice. Returns the faces and the replay token of the seed.
+#[pyfunction]
+#[pyo3(signature = (seed=None))]
+fn genius_square_roll(seed: Option<String>) -> (Vec<String>, String) {
+    let seed = seed_from_option(seed);
+    let faces = roll_dice(&mut seed.derive("dice"));
+    (faces, seed.to_token())
+}
+
+/// Solves the problem given by the blocked cells (e.g. `["B1", "C4", ...]`).
+///
+/// Returns a dictionary with the status, the solution (as rows of symbols and as numpy array of codes)
+/// and the statistics of the search.
+#[pyfunction]
+#[pyo3(signature = (roll, timeout_ms=None, max_nodes=None))]
+fn genius_square_solve<'py>(
+    py: Python<'py>,
+    roll: Vec<String>,
+    timeout_ms: Option<u64>,
+    max_nodes: Option<u64>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let board = create_board(&roll)?;
+    let options = create_options(timeout_ms, max_nodes);
+    let outcome = py.detach(|| solve_brute_force_with_options(&board, &options));
+    let result = outcome_to_dict(py, &outcome)?;
+    match &outcome.board {
+        Some(solution) => {
+            result.set_item("grid", solution.to_rows())?;
+            result.set_item("codes", PyArray2::from_owned_array(py, solution.to_codes()))?;
+        },
+        None => {
+            result.set_item("grid", py.None())?;
+            result.set_item("codes", py.None())?;
+        },
+    }
+    Ok(result)
+}
+
+/// Counts all solutions of the problem given by the blocked cells.
+///
+/// The count is only exact if `complete` is `True`.
+#[pyfunction]
+#[pyo3(signature = (roll, timeout_ms=None, max_nodes=None))]
+fn genius_square_count<'py>(
+    py: Python<'py>,
+    roll: Vec<String>,
+    timeout_ms: Option<u64>,
+    max_nodes: Option<u64>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let board = create_board(&roll)?;
+    let options = create_options(timeout_ms, max_nodes);
+    let outcome = py.detach(|| count_memoised_with_options(&board, &options));
+    let result = outcome_to_dict(py, &outcome)?;
+    result.set_item("count", outcome.stats.solutions)?;
+    result.set_item("complete", outcome.status.is_finished())?;
+    Ok(result)
+}
+
+/// ----------------------------------------------------------------
+/// METHODS - HACKERRANK
+/// ----------------------------------------------------------------
+
+/// Runs an approach to the Hackerrank challenge _Scalar Products_.
+#[pyfunction]
+#[pyo3(signature = (c, m, n, approach=2))]
+fn scalar_products_run(c: i64, m: i64, n: usize, approach: u8) -> PyResult<usize> {
+    match approach {
+        1 => {
+            let c = i32::try_from(c).map_err(|err| PyValueError::new_err(err.to_string()))?;
+            let m = i32::try_from(m).map_err(|err| PyValueError::new_err(err.to_string()))?;
+            Ok(scalar_products::approach1::run(c, m, n))
+        },
+        2 => Ok(scalar_products::approach2::run(c, m, n)),
+        _ => Err(PyValueError::new_err(format!("Unknown approach {approach}."))),
+    }
+}
+
+/// Runs the approach to the Hackerrank Project Euler challenge _Prime Summation_.
+#[pyfunction]
+fn prime_summation_run(numbers: Vec<i64>) -> HashMap<i64, i64> {
+    problem10_prime_summation::approach1::run(&numbers)
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+/// Symbols of the board, indexed by their codes
+fn genius_square_symbols() -> Vec<&'static str> {
+    ENUM_CELLS.iter().map(|kind| kind.as_str()).collect()
+}
+
+fn create_board(roll: &[String]) -> PyResult<GameBoard> {
+    let block = block_from_cells(roll).map_err(PyValueError::new_err)?;
+    Ok(GameBoard::new(&block))
+}
+
+fn create_options(timeout_ms: Option<u64>, max_nodes: Option<u64>) -> SolveOptions {
+    let mut options = SolveOptions::new();
+    if let Some(timeout_ms) = timeout_ms {
+        options = options.with_timeout(Duration::from_millis(timeout_ms));
+    }
+    if let Some(max_nodes) = max_nodes {
+        options = options.with_max_nodes(max_nodes);
+    }
+    options
+}
+
+fn outcome_to_dict<'py>(py: Python<'py>, outcome: &SolveOutcome) -> PyResult<Bound<'py, PyDict>> {
+    let result = PyDict::new(py);
+    result.set_item("status", outcome.status.as_str())?;
+    result.set_item("nodes", outcome.stats.nodes)?;
+    result.set_item("elapsed_ms", outcome.stats.elapsed.as_millis() as u64)?;
+    Ok(result)
+}
@@ -0,0 +1,81 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::ffi::c_char;
+use std::ptr;
+
+use super::capi::*;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_and_copy() {
+        let puzzle = create_puzzle(&["B1", "C4", "D6", "F1", "F2", "F3", "F5"]).unwrap();
+        let mut buffer = [0u8; GS_GRID_SIZE];
+        unsafe {
+            assert_eq!(gs_puzzle_copy_solution(puzzle, buffer.as_mut_ptr(), buffer.len()), GS_ERR_NOT_SOLVED);
+            assert_eq!(gs_puzzle_solve(puzzle, 0, 0), GS_OK);
+            assert_eq!(gs_puzzle_copy_solution(puzzle, buffer.as_mut_ptr(), 10), GS_ERR_BUFFER_TOO_SMALL);
+            assert_eq!(gs_puzzle_copy_solution(puzzle, buffer.as_mut_ptr(), buffer.len()), GS_OK);
+            gs_puzzle_free(puzzle);
+        }
+        // B1 is blocked, every other cell is covered by a piece
+        assert_eq!(buffer[1], 1);
+        assert_eq!(buffer.iter().filter(|&&code| code == 1).count(), 7);
+        assert!(buffer.iter().all(|&code| (1..=10).contains(&code)));
+    }
+
+    #[test]
+    fn test_limits() {
+        let puzzle = create_puzzle(&["B1", "C4", "D6", "F1", "F2", "F3", "F5"]).unwrap();
+        let mut count = u64::MAX;
+        unsafe {
+            assert_eq!(gs_puzzle_solve(puzzle, 0, 5), GS_ERR_LIMIT_REACHED);
+            assert_eq!(gs_puzzle_count(puzzle, 0, 5, &mut count), GS_ERR_LIMIT_REACHED);
+            gs_puzzle_free(puzzle);
+        }
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(create_puzzle(&["B1", "C4"]).err(), Some(GS_ERR_INVALID_FACES));
+        assert_eq!(create_puzzle(&["B1", "C4", "D6", "F1", "F2", "F3", "Z9"]).err(), Some(GS_ERR_INVALID_FACES));
+        assert_eq!(create_puzzle(&["B1", "B1", "D6", "F1", "F2", "F3", "F5"]).err(), Some(GS_ERR_INVALID_FACES));
+        // seven distinct cells, but C3 and D4 are on the same die only
+        assert_eq!(create_puzzle(&["A1", "B2", "C3", "D4", "E5", "F6", "A6"]).err(), Some(GS_ERR_INVALID_FACES));
+        // valid faces in the order of the dice
+        let puzzle = create_puzzle(&["F2", "F1", "F5", "B1", "F3", "C4", "D6"]).unwrap();
+        unsafe { gs_puzzle_free(puzzle) };
+        unsafe {
+            assert_eq!(gs_puzzle_new(ptr::null(), 7, ptr::null_mut()), GS_ERR_NULL_POINTER);
+            assert_eq!(gs_puzzle_solve(ptr::null_mut(), 0, 0), GS_ERR_NULL_POINTER);
+            gs_puzzle_free(ptr::null_mut());
+        }
+        let message = unsafe { CStr::from_ptr(gs_status_message(GS_ERR_INVALID_FACES)) };
+        assert_eq!(message.to_str(), Ok("invalid faces"));
+        let message = unsafe { CStr::from_ptr(gs_status_message(GS_ERR_UNSUPPORTED)) };
+        assert_eq!(message.to_str(), Ok("unsupported"));
+    }
+
+    fn create_puzzle(faces: &[&str]) -> Result<*mut GsPuzzle, i32> {
+        let faces: Vec<CString> = faces.iter().map(|face| CString::new(*face).unwrap()).collect();
+        let pointers: Vec<*const c_char> = faces.iter().map(|face| face.as_ptr()).collect();
+        let mut puzzle: *mut GsPuzzle = ptr::null_mut();
+        let code = unsafe { gs_puzzle_new(pointers.as_ptr(), pointers.len(), &mut puzzle) };
+        if code != GS_OK {
+            return Err(code);
+        }
+        return Ok(puzzle);
+    }
+}
@@ -0,0 +1,108 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::cnf::models::CnfEncoding;
+use crate::games::genius_square::models::cnf::models::CnfFormula;
+use crate::games::genius_square::models::constants::enums::ENUM_PIECES;
+use crate::games::genius_square::models::constants::enums::NON_ADJACENT;
+use crate::games::genius_square::models::pieces::models::Piece;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Encodes the remaining pieces of a game state as a SAT problem.
+///
+/// There is one variable per placement of a piece which is not yet on the board, and clauses for
+///
+/// - exactly one placement per piece,
+/// - exactly one placement covering each free cell,
+/// - no contact between two pieces in [`NON_ADJACENT`].
+///
+/// Every model of the formula corresponds to a solution
+/// (see [`CnfEncoding::decode_model`]).
+pub fn encode_cnf(board: &GameBoard) -> CnfEncoding {
+    // obstacle consisting of the blocked cells and all pieces on the board
+    let mut obst = board.get_block().to_owned();
+    for piece in board.get_pieces().values() {
+        obst = obst + piece.clone();
+    }
+
+    // enumerate the placements of the remaining pieces
+    let mut placements: Vec<Piece> = vec![];
+    let mut vars_by_piece: Vec<Vec<i64>> = vec![];
+    for kind in ENUM_PIECES {
+        if board.get_pieces().contains_key(kind) {
+            continue;
+        }
+        let piece0 = Piece::from_kind(kind, None);
+        let mut vars: Vec<i64> = vec![];
+        for piece in board.get_configurations(&piece0, &obst) {
+            placements.push(piece);
+            vars.push(placements.len() as i64);
+        }
+        vars_by_piece.push(vars);
+    }
+
+    let mut formula = CnfFormula::new(placements.len());
+    formula.add_comment("Genius Square");
+    for line in board.to_rows() {
+        formula.add_comment(&format!("|{line}|"));
+    }
+    for (k, piece) in placements.iter().enumerate() {
+        let cells: Vec<String> = board_cells(piece);
+        formula.add_comment(&format!("{} {} {}", k + 1, piece.get_kind().as_str(), cells.join(" ")));
+    }
+
+    // exactly one placement per piece
+    for vars in vars_by_piece.iter() {
+        formula.add_exactly_one(vars);
+    }
+
+    // exactly one placement per free cell
+    let mut vars_by_cell: HashMap<(usize, usize), Vec<i64>> = HashMap::new();
+    for (k, piece) in placements.iter().enumerate() {
+        for cell in piece.to_coords() {
+            vars_by_cell.entry(cell).or_default().push(k as i64 + 1);
+        }
+    }
+    let free = obst.get_positions().transform_invert();
+    for cell in free.to_coords() {
+        let vars = vars_by_cell.remove(&cell).unwrap_or_default();
+        formula.add_exactly_one(&vars);
+    }
+
+    // no contact between non-adjacent pieces
+    let dithered: Vec<Piece> = placements.iter().map(|piece| piece.transform_dither()).collect();
+    for (a, piece_a) in placements.iter().enumerate() {
+        let kind_a = piece_a.get_kind();
+        if !NON_ADJACENT.contains(&kind_a) {
+            continue;
+        }
+        for (b, piece_b) in placements.iter().enumerate().skip(a + 1) {
+            let kind_b = piece_b.get_kind();
+            if kind_b == kind_a || !NON_ADJACENT.contains(&kind_b) {
+                continue;
+            }
+            if dithered[a].get_positions().intersects(piece_b.get_positions()) {
+                formula.add_clause(vec![-(a as i64 + 1), -(b as i64 + 1)]);
+            }
+        }
+    }
+
+    return CnfEncoding::new(board, placements, formula);
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+/// Labels (e.g. `B2`) of the cells of a piece
+fn board_cells(piece: &Piece) -> Vec<String> {
+    let geometry = piece.get_positions().get_geometry();
+    piece.to_coords().iter().map(|&cell| geometry.label(cell)).collect()
+}
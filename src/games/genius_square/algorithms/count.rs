@@ -0,0 +1,426 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::constants::enums::ENUM_PIECES;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::constants::enums::NON_ADJACENT;
+use crate::games::genius_square::models::pieces::models::Piece;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveOutcome;
+use crate::games::genius_square::models::solver::models::SolveStats;
+use crate::games::genius_square::models::solver::models::SolveStatus;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+/// Largest number of cells of boards whose solutions can be counted
+pub const MAX_CELLS: usize = 64;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Counts all solutions exactly, subject to the limits in the options.
+///
+/// The cells are encoded as a bitmask and the lowest empty cell is always filled first,
+/// so that the number of completions only depends on
+///
+/// - the occupied cells,
+/// - the set of remaining pieces,
+/// - the cells next to already placed pieces in `NON_ADJACENT`
+///   (only if such pieces remain),
+///
+/// which is used to memoise the counts of partial states.
+///
+/// The count is contained in the statistics of the outcome
+/// and the board of the outcome is a solution (if one exists).
+/// If the search is aborted, the count is `0`.
+///
+/// Boards with more than [`MAX_CELLS`] cells are [unsupported](SolveStatus::Unsupported).
+pub fn count_memoised_with_options(
+    board: &GameBoard,
+    options: &SolveOptions,
+) -> SolveOutcome {
+    let t0 = Instant::now();
+    let Some(mut ctx) = CountContext::new(board, options) else {
+        return unsupported(t0);
+    };
+    let (occupied, remaining, forbidden) = ctx.initial_state(board);
+    let result = ctx.count(occupied, remaining, forbidden);
+
+    let (status, count, solution) = match result {
+        Ok(0) => (SolveStatus::Exhausted, 0, None),
+        Ok(count) => (SolveStatus::Solved, count, ctx.reconstruct(board, occupied, remaining, forbidden)),
+        Err(status) => (status, 0, None),
+    };
+    let stats = SolveStats {
+        nodes: ctx.nodes,
+        solutions: count,
+        elapsed: t0.elapsed(),
+    };
+    return SolveOutcome {status, board: solution, stats};
+}
+
+/// Calls the function on every solution,
+/// subject to the limits in the options (which apply to the counting).
+///
+/// Only branches with a positive (memoised) count are visited,
+/// so that the effort is proportional to the number of solutions.
+/// The outcome is as in [`count_memoised_with_options`],
+/// and no solutions are visited if the search is aborted.
+pub fn visit_memoised_with_options(
+    board: &GameBoard,
+    options: &SolveOptions,
+    mut f: impl FnMut(&GameBoard),
+) -> SolveOutcome {
+    let t0 = Instant::now();
+    let Some(mut ctx) = CountContext::new(board, options) else {
+        return unsupported(t0);
+    };
+    let (occupied, remaining, forbidden) = ctx.initial_state(board);
+    let result = ctx.count(occupied, remaining, forbidden);
+
+    let (status, count, solution) = match result {
+        Ok(0) => (SolveStatus::Exhausted, 0, None),
+        Ok(count) => {
+            ctx.visit(board, occupied, remaining, forbidden, &mut f);
+            (SolveStatus::Solved, count, ctx.reconstruct(board, occupied, remaining, forbidden))
+        },
+        Err(status) => (status, 0, None),
+    };
+    let stats = SolveStats {
+        nodes: ctx.nodes,
+        solutions: count,
+        elapsed: t0.elapsed(),
+    };
+    return SolveOutcome {status, board: solution, stats};
+}
+
+/// Chooses a solution uniformly at random from all solutions,
+/// subject to the limits in the options (which apply to the counting).
+///
+/// The solution is built cell by cell (as in [`count_memoised_with_options`]),
+/// where each placement is chosen with probability proportional
+/// to the number of solutions containing it.
+/// The outcome is as in [`count_memoised_with_options`]
+/// with the sampled solution as its board.
+pub fn sample_memoised_with_options(
+    board: &GameBoard,
+    options: &SolveOptions,
+    rng: &mut ChaCha8Rng,
+) -> SolveOutcome {
+    let (mut outcome, samples) = sample_many_memoised_with_options(board, options, rng, 1);
+    outcome.board = samples.into_iter().next();
+    return outcome;
+}
+
+/// Chooses solutions independently and uniformly at random (with repetition),
+/// sharing the counts between the samples (see [`sample_memoised_with_options`]).
+/// The board of the outcome is the first solution as in [`count_memoised_with_options`].
+pub fn sample_many_memoised_with_options(
+    board: &GameBoard,
+    options: &SolveOptions,
+    rng: &mut ChaCha8Rng,
+    num_samples: usize,
+) -> (SolveOutcome, Vec<GameBoard>) {
+    let t0 = Instant::now();
+    let Some(mut ctx) = CountContext::new(board, options) else {
+        return (unsupported(t0), vec![]);
+    };
+    let (occupied, remaining, forbidden) = ctx.initial_state(board);
+    let result = ctx.count(occupied, remaining, forbidden);
+
+    let mut samples = vec![];
+    let (status, count, solution) = match result {
+        Ok(0) => (SolveStatus::Exhausted, 0, None),
+        Ok(count) => {
+            for _ in 0..num_samples {
+                samples.extend(ctx.sample(board, occupied, remaining, forbidden, rng));
+            }
+            (SolveStatus::Solved, count, ctx.reconstruct(board, occupied, remaining, forbidden))
+        },
+        Err(status) => (status, 0, None),
+    };
+    let stats = SolveStats {
+        nodes: ctx.nodes,
+        solutions: count,
+        elapsed: t0.elapsed(),
+    };
+    return (SolveOutcome {status, board: solution, stats}, samples);
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Placement of a piece as bitmasks
+struct Placement {
+    /// index of the piece in the list of remaining pieces
+    index: usize,
+    cells: u64,
+    /// cells next to the placement
+    halo: u64,
+    non_adjacent: bool,
+    piece: Piece,
+}
+
+/// State shared across the recursion
+struct CountContext<'a> {
+    options: &'a SolveOptions,
+    /// number of columns of the board
+    n: usize,
+    /// mask of all cells
+    full: u64,
+    /// placements indexed by their lowest cell
+    placements: Vec<Vec<Placement>>,
+    /// mask of the remaining pieces in `NON_ADJACENT`
+    non_adjacent: u16,
+    memo: HashMap<(u64, u16, u64), u64>,
+    nodes: u64,
+}
+
+impl<'a> CountContext<'a> {
+    /// The context of a board (none if the board has more than [`MAX_CELLS`] cells)
+    fn new(board: &GameBoard, options: &'a SolveOptions) -> Option<Self> {
+        let (m, n) = board.get_shape();
+        if m * n > MAX_CELLS {
+            return None;
+        }
+        let full = if m * n == 64 { u64::MAX } else { (1u64 << (m * n)) - 1 };
+
+        let mut obst = board.get_block().to_owned();
+        for piece in board.get_pieces().values() {
+            obst = obst + piece.clone();
+        }
+
+        let mut placements: Vec<Vec<Placement>> = (0..m * n).map(|_| vec![]).collect();
+        let mut non_adjacent: u16 = 0;
+        for (index, kind) in remaining_kinds(board).iter().enumerate() {
+            let is_non_adjacent = NON_ADJACENT.contains(kind);
+            if is_non_adjacent {
+                non_adjacent |= 1 << index;
+            }
+            for piece in board.get_configurations(&board.create_piece(kind), &obst) {
+                let cells = to_mask(&piece, n);
+                let halo = to_mask(&piece.transform_dither(), n) & !cells;
+                let lowest = cells.trailing_zeros() as usize;
+                placements[lowest].push(Placement {index, cells, halo, non_adjacent: is_non_adjacent, piece});
+            }
+        }
+
+        return Some(Self {options, n, full, placements, non_adjacent, memo: HashMap::new(), nodes: 0});
+    }
+
+    /// Occupied cells, remaining pieces and forbidden cells of the board
+    fn initial_state(&self, board: &GameBoard) -> (u64, u16, u64) {
+        let mut occupied = to_mask(board.get_block(), self.n);
+        let mut forbidden: u64 = 0;
+        for (kind, piece) in board.get_pieces() {
+            occupied |= to_mask(piece, self.n);
+            if NON_ADJACENT.contains(kind) {
+                forbidden |= to_mask(&piece.transform_dither(), self.n);
+            }
+        }
+        let num_remaining = remaining_kinds(board).len();
+        let remaining: u16 = ((1u32 << num_remaining) - 1) as u16;
+        return (occupied, remaining, forbidden);
+    }
+
+    /// Number of ways to complete the state
+    fn count(
+        &mut self,
+        occupied: u64,
+        remaining: u16,
+        forbidden: u64,
+    ) -> Result<u64, SolveStatus> {
+        if occupied == self.full {
+            return Ok(if remaining == 0 { 1 } else { 0 });
+        }
+        if remaining == 0 {
+            return Ok(0);
+        }
+        let forbidden = self.relevant(occupied, remaining, forbidden);
+        if let Some(&count) = self.memo.get(&(occupied, remaining, forbidden)) {
+            return Ok(count);
+        }
+
+        let mut total: u64 = 0;
+        let lowest = (!occupied & self.full).trailing_zeros() as usize;
+        for k in 0..self.placements[lowest].len() {
+            let Some((occupied_, remaining_, forbidden_)) = self.apply(lowest, k, occupied, remaining, forbidden) else {
+                continue;
+            };
+            // abort if any of the limits have been reached
+            if let Some(status) = self.options.check_limits(self.nodes) {
+                return Err(status);
+            }
+            self.nodes += 1;
+            total += self.count(occupied_, remaining_, forbidden_)?;
+        }
+
+        self.memo.insert((occupied, remaining, forbidden), total);
+        return Ok(total);
+    }
+
+    /// Follows the memoised counts to a solution
+    fn reconstruct(
+        &mut self,
+        board: &GameBoard,
+        mut occupied: u64,
+        mut remaining: u16,
+        mut forbidden: u64,
+    ) -> Option<GameBoard> {
+        let mut solution = board.clone();
+        while occupied != self.full {
+            let lowest = (!occupied & self.full).trailing_zeros() as usize;
+            let forbidden_ = self.relevant(occupied, remaining, forbidden);
+            let k = (0..self.placements[lowest].len()).find(|&k| {
+                match self.apply(lowest, k, occupied, remaining, forbidden_) {
+                    Some((o, r, f)) => self.count(o, r, f).unwrap_or(0) > 0,
+                    None => false,
+                }
+            })?;
+            let placement = &self.placements[lowest][k];
+            solution.add_piece(&placement.piece.get_kind(), &placement.piece);
+            (occupied, remaining, forbidden) = self.apply(lowest, k, occupied, remaining, forbidden_)?;
+        }
+        return Some(solution);
+    }
+
+    /// Follows the memoised counts to a random solution,
+    /// choosing each placement with probability proportional to its count
+    fn sample(
+        &mut self,
+        board: &GameBoard,
+        mut occupied: u64,
+        mut remaining: u16,
+        mut forbidden: u64,
+        rng: &mut ChaCha8Rng,
+    ) -> Option<GameBoard> {
+        let mut solution = board.clone();
+        while occupied != self.full {
+            let lowest = (!occupied & self.full).trailing_zeros() as usize;
+            let forbidden_ = self.relevant(occupied, remaining, forbidden);
+            let total = self.count(occupied, remaining, forbidden_).ok()?;
+            if total == 0 {
+                return None;
+            }
+            let mut r = rng.random_range(0..total);
+            let mut chosen = None;
+            for k in 0..self.placements[lowest].len() {
+                let Some(state) = self.apply(lowest, k, occupied, remaining, forbidden_) else {
+                    continue;
+                };
+                let (o, r_, f) = state;
+                let count = self.count(o, r_, f).ok()?;
+                if r < count {
+                    chosen = Some((k, state));
+                    break;
+                }
+                r -= count;
+            }
+            let (k, state) = chosen?;
+            let placement = &self.placements[lowest][k];
+            solution.add_piece(&placement.piece.get_kind(), &placement.piece);
+            (occupied, remaining, forbidden) = state;
+        }
+        return Some(solution);
+    }
+
+    /// Visits all solutions following the memoised counts
+    fn visit(
+        &mut self,
+        board: &GameBoard,
+        occupied: u64,
+        remaining: u16,
+        forbidden: u64,
+        f: &mut impl FnMut(&GameBoard),
+    ) {
+        if occupied == self.full {
+            if remaining == 0 {
+                f(board);
+            }
+            return;
+        }
+        let lowest = (!occupied & self.full).trailing_zeros() as usize;
+        let forbidden = self.relevant(occupied, remaining, forbidden);
+        for k in 0..self.placements[lowest].len() {
+            let Some((occupied_, remaining_, forbidden_)) = self.apply(lowest, k, occupied, remaining, forbidden) else {
+                continue;
+            };
+            // skip dead ends (all states below have been counted)
+            if self.count(occupied_, remaining_, forbidden_).unwrap_or(0) == 0 {
+                continue;
+            }
+            let piece = &self.placements[lowest][k].piece;
+            let mut board_ = board.clone();
+            board_.add_piece(&piece.get_kind(), piece);
+            self.visit(&board_, occupied_, remaining_, forbidden_, f);
+        }
+    }
+
+    /// State after the `k`-th placement at the lowest cell, if it is permissible
+    fn apply(
+        &self,
+        lowest: usize,
+        k: usize,
+        occupied: u64,
+        remaining: u16,
+        forbidden: u64,
+    ) -> Option<(u64, u16, u64)> {
+        let placement = &self.placements[lowest][k];
+        if remaining & (1 << placement.index) == 0 || placement.cells & occupied != 0 {
+            return None;
+        }
+        if !placement.non_adjacent {
+            return Some((occupied | placement.cells, remaining & !(1 << placement.index), forbidden));
+        }
+        if placement.cells & forbidden != 0 {
+            return None;
+        }
+        return Some((occupied | placement.cells, remaining & !(1 << placement.index), forbidden | placement.halo));
+    }
+
+    /// Restricts the forbidden cells to those which still matter
+    fn relevant(&self, occupied: u64, remaining: u16, forbidden: u64) -> u64 {
+        if remaining & self.non_adjacent == 0 {
+            return 0;
+        }
+        return forbidden & !occupied;
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+/// Outcome for boards which are too large to be counted
+fn unsupported(t0: Instant) -> SolveOutcome {
+    let stats = SolveStats {elapsed: t0.elapsed(), ..SolveStats::default()};
+    return SolveOutcome {status: SolveStatus::Unsupported, board: None, stats};
+}
+
+/// Pieces which are not yet on the board
+fn remaining_kinds(board: &GameBoard) -> Vec<EnumPiece> {
+    ENUM_PIECES
+        .iter()
+        .filter(|kind| !board.get_pieces().contains_key(kind))
+        .cloned()
+        .collect()
+}
+
+/// Bitmask of the cells of a piece (bit `i * n + j` for the cell `(i, j)`)
+fn to_mask(piece: &Piece, n: usize) -> u64 {
+    piece
+        .to_coords()
+        .iter()
+        .fold(0, |mask, &(i, j)| mask | (1u64 << (i * n + j)))
+}
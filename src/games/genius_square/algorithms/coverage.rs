@@ -0,0 +1,31 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use crate::games::genius_square::algorithms::count::visit_memoised_with_options;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::coverage::models::Coverage;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveStatus;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Determines how often each piece covers each free cell across all solutions,
+/// subject to the limits in the options.
+///
+/// Returns the status of the search if it had to be aborted.
+pub fn compute_coverage_with_options(
+    board: &GameBoard,
+    options: &SolveOptions,
+) -> Result<Coverage, SolveStatus> {
+    let mut coverage = Coverage::new(board);
+    let outcome = visit_memoised_with_options(board, options, |solution| {
+        coverage.add_solution(solution);
+    });
+    return match outcome.status {
+        SolveStatus::Solved | SolveStatus::Exhausted => Ok(coverage),
+        status => Err(status),
+    };
+}
@@ -0,0 +1,143 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use itertools::Itertools;
+
+use crate::games::genius_square::models::board::methods::parse_cell;
+use crate::games::genius_square::models::constants::dice::DICE;
+use crate::games::genius_square::models::dice::models::Die;
+use crate::games::genius_square::models::dice::models::LayoutMatch;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Determines all rolls of the dice in [`DICE`] which produce the layout of blocked cells
+/// and the probability of the layout under fair dice.
+pub fn match_layout(cells: &[String]) -> Result<LayoutMatch, String> {
+    match_layout_with_dice(cells, DICE)
+}
+
+/// Determines all rolls of the given dice which produce the layout of blocked cells
+/// (one cell per die) and the probability of the layout under fair dice.
+///
+/// The rolls are the perfect matchings between the dice and the cells
+/// in the bipartite graph in which a die is joined to every cell on its faces.
+/// They are enumerated die by die, where a face is only tried
+/// if the remaining dice can still be matched to the remaining cells.
+pub fn match_layout_with_dice(
+    cells: &[String],
+    dice: &[&[&str]],
+) -> Result<LayoutMatch, String> {
+    let cells: Vec<String> = cells
+        .iter()
+        .map(|cell| parse_cell(cell).map(|(i, j)| Die::from_coords(i, j).to_string()))
+        .collect::<Result<Vec<String>, String>>()?;
+    if cells.len() != dice.len() {
+        return Err(format!("Expected {} blocked cells, got {}.", dice.len(), cells.len()));
+    }
+    if cells.iter().unique().count() != cells.len() {
+        return Err("The blocked cells must be distinct.".to_string());
+    }
+
+    // cells which can be shown by each die
+    let edges: Vec<Vec<usize>> = dice
+        .iter()
+        .map(|die| (0..cells.len()).filter(|&c| die.contains(&cells[c].as_str())).collect())
+        .collect();
+
+    let mut result = LayoutMatch {assignments: vec![], probability: 0.};
+    let mut chosen: Vec<usize> = vec![];
+    let mut used = vec![false; cells.len()];
+    if has_matching(&edges, 0, &used) {
+        enumerate(&edges, &mut chosen, &mut used, &mut |chosen| {
+            let probability: f64 = chosen
+                .iter()
+                .zip(dice.iter())
+                .map(|(&c, die)| {
+                    let num_faces = die.iter().filter(|&&face| face == cells[c]).count();
+                    num_faces as f64 / die.len() as f64
+                })
+                .product();
+            result.assignments.push(chosen.iter().map(|&c| cells[c].clone()).collect());
+            result.probability += probability;
+        });
+    }
+    return Ok(result);
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+/// Assigns cells to the dice from `chosen.len()` onwards
+/// and calls the function on every complete assignment
+fn enumerate(
+    edges: &[Vec<usize>],
+    chosen: &mut Vec<usize>,
+    used: &mut [bool],
+    f: &mut impl FnMut(&[usize]),
+) {
+    let k = chosen.len();
+    if k == edges.len() {
+        f(chosen);
+        return;
+    }
+    for &c in edges[k].iter() {
+        if used[c] {
+            continue;
+        }
+        used[c] = true;
+        chosen.push(c);
+        // prune assignments which cannot be completed
+        if has_matching(edges, k + 1, used) {
+            enumerate(edges, chosen, used, f);
+        }
+        chosen.pop();
+        used[c] = false;
+    }
+}
+
+/// Whether the dice from `start` onwards can be matched to distinct unused cells
+/// (via augmenting paths)
+fn has_matching(
+    edges: &[Vec<usize>],
+    start: usize,
+    used: &[bool],
+) -> bool {
+    // die matched to each cell
+    let mut matched: Vec<Option<usize>> = vec![None; used.len()];
+    for k in start..edges.len() {
+        let mut visited = vec![false; used.len()];
+        if !augment(edges, k, used, &mut matched, &mut visited) {
+            return false;
+        }
+    }
+    return true;
+}
+
+/// Tries to match the die to a cell, re-matching other dice if necessary
+fn augment(
+    edges: &[Vec<usize>],
+    k: usize,
+    used: &[bool],
+    matched: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for &c in edges[k].iter() {
+        if used[c] || visited[c] {
+            continue;
+        }
+        visited[c] = true;
+        let free = match matched[c] {
+            None => true,
+            Some(other) => augment(edges, other, used, matched, visited),
+        };
+        if free {
+            matched[c] = Some(k);
+            return true;
+        }
+    }
+    return false;
+}
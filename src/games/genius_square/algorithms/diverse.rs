@@ -0,0 +1,129 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use ndarray::Array2;
+
+use crate::games::genius_square::algorithms::count::visit_memoised_with_options;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveStatus;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+/// Maximal number of passes which try to improve the greedy choice
+const MAX_IMPROVEMENTS: usize = 10;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Chooses up to `k` solutions which differ from each other as much as possible,
+/// subject to the limits in the options (which apply to the counting).
+///
+/// The distance of two solutions is the number of cells covered by different pieces
+/// (see [`solution_distance`]) and the smallest distance between chosen solutions is maximised:
+///
+/// - greedily, i.e. the next solution is the one furthest from those already chosen,
+///   starting from one end of a long distance,
+/// - followed by passes which swap chosen solutions for better ones
+///   (ties are broken by the sum of the distances).
+///
+/// Since all solutions are compared, the effort grows with the number of solutions.
+/// Returns the status of the search if it had to be aborted.
+pub fn find_diverse_with_options(
+    board: &GameBoard,
+    options: &SolveOptions,
+    k: usize,
+) -> Result<Vec<GameBoard>, SolveStatus> {
+    let mut solutions: Vec<GameBoard> = vec![];
+    let outcome = visit_memoised_with_options(board, options, |solution| {
+        solutions.push(solution.clone());
+    });
+    match outcome.status {
+        SolveStatus::Solved | SolveStatus::Exhausted => {},
+        status => return Err(status),
+    }
+    if k == 0 || solutions.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let codes: Vec<Array2<u8>> = solutions.iter().map(|solution| solution.to_codes()).collect();
+    let distance = |a: usize, b: usize| count_differences(&codes[a], &codes[b]);
+    let mut chosen = choose_greedily(codes.len(), k, distance);
+    improve(&mut chosen, codes.len(), distance);
+    let result = chosen.iter().map(|&index| solutions[index].clone()).collect();
+    return Ok(result);
+}
+
+/// Number of cells which are covered by different pieces in the two boards.
+pub fn solution_distance(board1: &GameBoard, board2: &GameBoard) -> usize {
+    count_differences(&board1.to_codes(), &board2.to_codes())
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+fn count_differences(codes1: &Array2<u8>, codes2: &Array2<u8>) -> usize {
+    codes1.iter().zip(codes2.iter()).filter(|(a, b)| a != b).count()
+}
+
+/// Farthest-point selection of up to `k` of the `n` items.
+fn choose_greedily(n: usize, k: usize, distance: impl Fn(usize, usize) -> usize) -> Vec<usize> {
+    // start at the item furthest from an arbitrary item,
+    // which lies at one end of a long distance
+    let start = (0..n).max_by_key(|&index| (distance(0, index), usize::MAX - index)).unwrap();
+    let mut chosen = vec![start];
+    let mut nearest: Vec<usize> = (0..n).map(|index| distance(start, index)).collect();
+    while chosen.len() < k {
+        let (next, &gap) = nearest.iter().enumerate().max_by_key(|&(index, &gap)| (gap, usize::MAX - index)).unwrap();
+        if gap == 0 {
+            // only copies of chosen items are left
+            break;
+        }
+        chosen.push(next);
+        for (index, gap) in nearest.iter_mut().enumerate() {
+            *gap = (*gap).min(distance(next, index));
+        }
+    }
+    return chosen;
+}
+
+/// Swaps chosen items for other items, as long as this increases
+/// the smallest distance to the other chosen items (or their sum, if equal).
+fn improve(chosen: &mut [usize], n: usize, distance: impl Fn(usize, usize) -> usize) {
+    if chosen.len() < 2 {
+        return;
+    }
+    let score = |chosen: &[usize], skip: usize, candidate: usize| -> (usize, usize) {
+        let distances = chosen
+            .iter()
+            .enumerate()
+            .filter(|&(position, _)| position != skip)
+            .map(|(_, &other)| distance(candidate, other));
+        let min = distances.clone().min().unwrap();
+        return (min, distances.sum());
+    };
+    for _ in 0..MAX_IMPROVEMENTS {
+        let mut improved = false;
+        for position in 0..chosen.len() {
+            let current = score(chosen, position, chosen[position]);
+            let best = (0..n)
+                .filter(|index| !chosen.contains(index))
+                .map(|index| (score(chosen, position, index), index))
+                .max_by_key(|&(score, index)| (score, usize::MAX - index));
+            if let Some((score, index)) = best {
+                if score > current {
+                    chosen[position] = index;
+                    improved = true;
+                }
+            }
+        }
+        if !improved {
+            break;
+        }
+    }
+}
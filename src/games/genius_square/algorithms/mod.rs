@@ -0,0 +1,38 @@
+/// Algorithms used to solve a game state.
+
+pub mod cnf;
+pub mod count;
+pub mod coverage;
+pub mod dice;
+pub mod diverse;
+pub mod propagate;
+pub mod solve;
+pub mod solvers;
+pub mod validate;
+
+#[cfg(test)]
+pub mod tests_cnf;
+
+#[cfg(test)]
+pub mod tests_count;
+
+#[cfg(test)]
+pub mod tests_coverage;
+
+#[cfg(test)]
+pub mod tests_dice;
+
+#[cfg(test)]
+pub mod tests_diverse;
+
+#[cfg(test)]
+pub mod tests_propagate;
+
+#[cfg(test)]
+pub mod tests_solve;
+
+#[cfg(test)]
+pub mod tests_solvers;
+
+#[cfg(test)]
+pub mod tests_validate;
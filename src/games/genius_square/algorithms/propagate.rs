@@ -0,0 +1,476 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::time::Instant;
+
+use indicatif::ProgressBar;
+use indicatif::ProgressStyle;
+
+use crate::games::genius_square::models::arrays::masks::CellMask;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::constants::enums::ENUM_PIECES;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::constants::enums::NON_ADJACENT;
+use crate::games::genius_square::models::deduction::models::Contradiction;
+use crate::games::genius_square::models::deduction::models::Deduction;
+use crate::games::genius_square::models::deduction::models::DeductionReason;
+use crate::games::genius_square::models::pieces::models::Piece;
+use crate::games::genius_square::models::solver::models::SearchNode;
+use crate::games::genius_square::models::solver::models::SearchTreeRecorder;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveOutcome;
+use crate::games::genius_square::models::solver::models::SolveStats;
+use crate::games::genius_square::models::solver::models::SolveStatus;
+use crate::models::tree::base::GenericTree;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Places the remaining pieces as far as this follows logically from the board,
+/// i.e. repeats the deductions
+///
+/// - a free cell which only one placement covers forces that placement,
+/// - a piece with only one legal placement forces that placement,
+///
+/// until nothing changes.
+///
+/// Returns the board with the forced placements in the order in which they were deduced,
+/// or the reason why the board has no solution.
+/// The deductions do not depend on the order of the remaining pieces.
+pub fn deduce(board: &GameBoard) -> Result<(GameBoard, Vec<Deduction>), Contradiction> {
+    let catalogue = Catalogue::new(board);
+    let mut state = State::new(&catalogue);
+    let mut deductions: Vec<Deduction> = vec![];
+    state.propagate(&catalogue, &mut deductions)?;
+    return Ok((state.to_board(&catalogue), deductions));
+}
+
+/// Solves by propagating forced placements (see [`deduce`]) before each branching,
+/// subject to the limits in the options.
+///
+/// The search branches on the piece or free cell with the fewest placements left
+/// and abandons a branch as soon as a contradiction arises.
+/// Pieces already on the board are kept.
+/// Only the branches count as nodes, i.e. forced placements come for free.
+pub fn solve_propagating_with_options(
+    board: &GameBoard,
+    options: &SolveOptions,
+) -> SolveOutcome {
+    return search(board, options, false, false, SearchTreeRecorder::default()).0;
+}
+
+/// Counts all solutions by propagating forced placements before each branching
+/// (cf. [`solve_propagating_with_options`]).
+///
+/// The count is contained in the statistics of the outcome
+/// and is only exact if the search finished.
+pub fn count_propagating_with_options(
+    board: &GameBoard,
+    options: &SolveOptions,
+) -> SolveOutcome {
+    return search(board, options, true, false, SearchTreeRecorder::default()).0;
+}
+
+/// Finds all solutions by propagating forced placements before each branching
+/// (cf. [`solve_propagating_with_options`]).
+pub fn solve_all_propagating_with_options(
+    board: &GameBoard,
+    options: &SolveOptions,
+) -> (SolveOutcome, Vec<GameBoard>) {
+    let (outcome, all, _) = search(board, options, true, true, SearchTreeRecorder::default());
+    return (outcome, all);
+}
+
+/// Solves by propagating forced placements before each branching
+/// (cf. [`solve_propagating_with_options`]) and records the explored search tree.
+///
+/// Each node of the tree is a placement branched on
+/// and records whether a solution was found below it,
+/// where the forced placements are not part of the tree.
+/// Placements deeper than the given depth are not recorded,
+/// but counted in their recorded ancestor instead.
+pub fn solve_propagating_with_tree(
+    board: &GameBoard,
+    options: &SolveOptions,
+    max_depth: Option<usize>,
+) -> (SolveOutcome, GenericTree<SearchNode>) {
+    let recorder = SearchTreeRecorder::new(max_depth);
+    let (outcome, _, tree) = search(board, options, false, false, recorder);
+    return (outcome, tree.unwrap());
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Placement of a piece as bitmasks
+struct Placement {
+    cells: CellMask,
+    /// the cells and the cells next to them
+    dither: CellMask,
+    coords: Vec<(usize, usize)>,
+    piece: Piece,
+}
+
+/// Placements of the pieces which are not on the initial board,
+/// computed once, so that the search only has to filter them.
+struct Catalogue<'a> {
+    /// the initial board
+    board: &'a GameBoard,
+    /// the free cells of the initial board (row by row)
+    free: Vec<(usize, usize)>,
+    kinds: Vec<EnumPiece>,
+    /// placements of each piece on the initial board
+    placements: Vec<Vec<Placement>>,
+    /// whether each piece must not touch other such pieces
+    non_adjacent: Vec<bool>,
+}
+
+/// Partially solved game state.
+///
+/// The board is only built for solutions, so that branching copies as little as possible.
+#[derive(Clone)]
+struct State {
+    /// index of the placement of each piece, if placed
+    chosen: Vec<Option<usize>>,
+    /// the blocked and covered cells
+    occupied: CellMask,
+    /// the cells covered by pieces in `NON_ADJACENT`
+    occupied_non_adjacent: CellMask,
+}
+
+/// Legal placements of the remaining pieces
+struct Candidates {
+    /// index of each remaining piece with the indices of its legal placements
+    placements: Vec<(usize, Vec<usize>)>,
+    covers: Vec<Cover>,
+}
+
+/// A free cell with the number of legal placements covering it
+/// and one of them (piece index, placement index)
+type Cover = ((usize, usize), usize, (usize, usize));
+
+/// State shared across the recursion
+struct SearchContext<'a> {
+    options: &'a SolveOptions,
+    catalogue: Catalogue<'a>,
+    pbar: ProgressBar,
+    /// whether to continue after the first solution
+    find_all: bool,
+    first: Option<GameBoard>,
+    /// all solutions (only if they are collected)
+    all: Option<Vec<GameBoard>>,
+    nodes: u64,
+    solutions: u64,
+    /// the search tree (only if it is recorded)
+    tree: SearchTreeRecorder,
+}
+
+impl<'a> Catalogue<'a> {
+    fn new(board: &'a GameBoard) -> Self {
+        let (m, n) = board.get_shape();
+        let mut obst = board.get_block().clone();
+        for piece in board.get_pieces().values() {
+            obst = obst + piece.clone();
+        }
+        let free = (0..m * n)
+            .map(|k| (k / n, k % n))
+            .filter(|&cell| !obst.get_positions().contains(cell))
+            .collect();
+
+        let kinds: Vec<EnumPiece> = ENUM_PIECES
+            .iter()
+            .filter(|kind| !board.get_pieces().contains_key(kind))
+            .cloned()
+            .collect();
+        // NOTE: the order of the configurations is kept, so that the search does not depend on the catalogue
+        let placements = kinds
+            .iter()
+            .map(|kind| {
+                board
+                    .get_configurations(&board.create_piece(kind), &obst)
+                    .map(|piece| {
+                        let coords = piece.to_coords();
+                        Placement {
+                            cells: CellMask::from_coords(&coords, m, n),
+                            dither: CellMask::from_coords(&piece.transform_dither().to_coords(), m, n),
+                            coords,
+                            piece,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let non_adjacent = kinds.iter().map(|kind| NON_ADJACENT.contains(kind)).collect();
+        return Self {board, free, kinds, placements, non_adjacent};
+    }
+}
+
+impl State {
+    /// The initial board of the catalogue
+    fn new(catalogue: &Catalogue) -> Self {
+        let (m, n) = catalogue.board.get_shape();
+        let mut occupied = CellMask::full(m, n);
+        occupied.remove(&CellMask::from_coords(&catalogue.free, m, n));
+        return Self {
+            chosen: vec![None; catalogue.kinds.len()],
+            occupied,
+            occupied_non_adjacent: CellMask::empty(m, n),
+        };
+    }
+
+    /// Places a piece (the `l`-th placement of the `k`-th piece).
+    fn place(&mut self, catalogue: &Catalogue, k: usize, l: usize) {
+        let cells = &catalogue.placements[k][l].cells;
+        self.chosen[k] = Some(l);
+        self.occupied.insert(cells);
+        if catalogue.non_adjacent[k] {
+            self.occupied_non_adjacent.insert(cells);
+        }
+    }
+
+    fn num_remaining(&self) -> usize {
+        self.chosen.iter().filter(|l| l.is_none()).count()
+    }
+
+    /// The initial board with the pieces placed so far
+    fn to_board(&self, catalogue: &Catalogue) -> GameBoard {
+        let mut board = catalogue.board.clone();
+        for (placements, l) in catalogue.placements.iter().zip(self.chosen.iter()) {
+            if let Some(l) = *l {
+                let piece = &placements[l].piece;
+                board.add_piece(&piece.get_kind(), piece);
+            }
+        }
+        return board;
+    }
+
+    /// Whether a placement neither collides with nor touches forbidden pieces
+    fn is_legal(&self, placement: &Placement, non_adjacent: bool) -> bool {
+        let collides = placement.cells.intersects(&self.occupied);
+        let touches = non_adjacent && placement.dither.intersects(&self.occupied_non_adjacent);
+        return !(collides || touches);
+    }
+
+    fn get_candidates(&self, catalogue: &Catalogue) -> Candidates {
+        let placements: Vec<(usize, Vec<usize>)> = (0..catalogue.kinds.len())
+            .filter(|&k| self.chosen[k].is_none())
+            .map(|k| {
+                let legal = catalogue.placements[k]
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, placement)| self.is_legal(placement, catalogue.non_adjacent[k]))
+                    .map(|(l, _)| l)
+                    .collect();
+                (k, legal)
+            })
+            .collect();
+
+        let (m, n) = catalogue.board.get_shape();
+        let mut counts: Vec<(usize, (usize, usize))> = vec![(0, (0, 0)); m * n];
+        for (k, legal) in placements.iter() {
+            for &l in legal.iter() {
+                for &(i, j) in catalogue.placements[*k][l].coords.iter() {
+                    let count = &mut counts[i * n + j];
+                    if count.0 == 0 {
+                        count.1 = (*k, l);
+                    }
+                    count.0 += 1;
+                }
+            }
+        }
+        let covers = catalogue
+            .free
+            .iter()
+            .filter(|&&cell| !self.occupied.contains(cell))
+            .map(|&(i, j)| {
+                let (count, first) = counts[i * n + j];
+                ((i, j), count, first)
+            })
+            .collect();
+        return Candidates {placements, covers};
+    }
+
+    /// Places forced pieces until nothing changes
+    /// and returns the legal placements of the remaining pieces.
+    fn propagate(
+        &mut self,
+        catalogue: &Catalogue,
+        deductions: &mut Vec<Deduction>,
+    ) -> Result<Candidates, Contradiction> {
+        loop {
+            let candidates = self.get_candidates(catalogue);
+            if let Some((k, _)) = candidates.placements.iter().find(|(_, legal)| legal.is_empty()) {
+                return Err(Contradiction::UnplaceablePiece(catalogue.kinds[*k].clone()));
+            }
+            if let Some((cell, _, _)) = candidates.covers.iter().find(|(_, count, _)| *count == 0) {
+                return Err(Contradiction::UncoverableCell(*cell));
+            }
+
+            let forced = candidates
+                .placements
+                .iter()
+                .find(|(_, legal)| legal.len() == 1)
+                .map(|(k, legal)| (*k, legal[0], DeductionReason::OnlyPlacement))
+                .or_else(|| {
+                    candidates
+                        .covers
+                        .iter()
+                        .find(|(_, count, _)| *count == 1)
+                        .map(|&(cell, _, (k, l))| (k, l, DeductionReason::OnlyCover(cell)))
+                });
+            match forced {
+                Some((k, l, reason)) => {
+                    self.place(catalogue, k, l);
+                    deductions.push(Deduction::new(&catalogue.placements[k][l].piece, reason));
+                },
+                None => {
+                    return Ok(candidates);
+                },
+            }
+        }
+    }
+}
+
+impl Candidates {
+    /// Placements to branch on, i.e. those of the piece or cell with the fewest placements
+    /// (as pairs of piece index and placement index).
+    fn get_branches(&self, catalogue: &Catalogue) -> Vec<(usize, usize)> {
+        let piece = self.placements.iter().min_by_key(|(_, legal)| legal.len());
+        let cell = self.covers.iter().min_by_key(|(_, count, _)| *count);
+        return match (piece, cell) {
+            (Some((_, legal)), Some(&(cell, count, _))) if count < legal.len() => {
+                self.placements
+                    .iter()
+                    .flat_map(|(k, legal)| legal.iter().map(move |&l| (*k, l)))
+                    .filter(|&(k, l)| catalogue.placements[k][l].cells.contains(cell))
+                    .collect()
+            },
+            (Some((k, legal)), _) => {
+                legal.iter().map(|&l| (*k, l)).collect()
+            },
+            (None, _) => vec![],
+        };
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+fn search(
+    board: &GameBoard,
+    options: &SolveOptions,
+    find_all: bool,
+    collect: bool,
+    tree: SearchTreeRecorder,
+) -> (SolveOutcome, Vec<GameBoard>, Option<GenericTree<SearchNode>>) {
+    let t0 = Instant::now();
+    let catalogue = Catalogue::new(board);
+    let state = State::new(&catalogue);
+    let pbar = if options.get_progress() {
+        let pbar = ProgressBar::new(ENUM_PIECES.len() as u64);
+        let style = ProgressStyle::with_template("{spinner:.white} [{elapsed_precise}] [{wide_bar:.white}] {pos}/{len} ({eta_precise})");
+        pbar.set_style(style.unwrap());
+        pbar
+    } else {
+        ProgressBar::hidden()
+    };
+    let mut ctx = SearchContext {
+        options,
+        catalogue,
+        pbar,
+        find_all,
+        first: None,
+        all: if collect { Some(vec![]) } else { None },
+        nodes: 0,
+        solutions: 0,
+        tree,
+    };
+
+    let result = recursion(state, 0, &mut ctx);
+    ctx.pbar.finish_and_clear();
+    let stats = SolveStats {
+        nodes: ctx.nodes,
+        solutions: ctx.solutions,
+        elapsed: t0.elapsed(),
+    };
+    if options.get_progress() {
+        println!("...completed in {:.2?}", stats.elapsed);
+    }
+
+    let status = match result {
+        Ok(_) if ctx.first.is_some() => SolveStatus::Solved,
+        Ok(_) => SolveStatus::Exhausted,
+        Err(status) => status,
+    };
+    let board = ctx.first.take();
+    let all = ctx.all.take().unwrap_or_default();
+    let tree = ctx.tree.finish(ctx.solutions > 0, ctx.nodes);
+    return (SolveOutcome {status, board, stats}, all, tree);
+}
+
+/// Solutions are recorded in the context. Returns
+///
+/// - `Ok(true)` if the search is done (first solution found),
+/// - `Ok(false)` if the search has to continue,
+/// - `Err(status)` if the search had to be aborted.
+fn recursion(
+    mut state: State,
+    depth: usize,
+    ctx: &mut SearchContext,
+) -> std::result::Result<bool, SolveStatus> {
+    if let Some(status) = ctx.options.check_limits(ctx.nodes) {
+        return Err(status);
+    }
+    // NOTE: the deductions themselves are only of interest to players
+    let mut deductions: Vec<Deduction> = vec![];
+    let Ok(candidates) = state.propagate(&ctx.catalogue, &mut deductions) else {
+        return Ok(false);
+    };
+    let num_remaining = state.num_remaining();
+    ctx.pbar.set_position((ENUM_PIECES.len() - num_remaining) as u64);
+
+    if num_remaining == 0 {
+        // propagation ensures that every cell is covered
+        ctx.solutions += 1;
+        if ctx.first.is_none() {
+            ctx.first = Some(state.to_board(&ctx.catalogue));
+        }
+        if let Some(all) = ctx.all.as_mut() {
+            all.push(state.to_board(&ctx.catalogue));
+        }
+        return Ok(!ctx.find_all);
+    }
+
+    for (k, l) in candidates.get_branches(&ctx.catalogue) {
+        // abort if any of the limits have been reached
+        if let Some(status) = ctx.options.check_limits(ctx.nodes) {
+            return Err(status);
+        }
+        ctx.nodes += 1;
+
+        // open a node of the search tree
+        let record = ctx.tree.records(depth);
+        let (nodes, solutions) = (ctx.nodes, ctx.solutions);
+        if record {
+            let node = SearchNode::from_piece(&ctx.catalogue.placements[k][l].piece);
+            ctx.tree.open(node);
+        }
+
+        let mut state_ = state.clone();
+        state_.place(&ctx.catalogue, k, l);
+        let result = recursion(state_, depth + 1, ctx);
+
+        // close the node of the search tree
+        if record {
+            ctx.tree.close(depth, ctx.solutions > solutions, ctx.nodes - nodes);
+        }
+        if result? {
+            return Ok(true);
+        }
+    }
+    return Ok(false);
+}
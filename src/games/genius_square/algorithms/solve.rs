@@ -0,0 +1,354 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::time::Instant;
+
+use indicatif::ProgressBar;
+use indicatif::ProgressStyle;
+
+use crate::games::genius_square::models::arrays::masks::CellMask;
+use crate::games::genius_square::models::constants::enums::ENUM_PIECES;
+use crate::games::genius_square::models::constants::enums::NON_ADJACENT;
+use crate::games::genius_square::models::pieces::models::Piece;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::solver::models::SearchNode;
+use crate::games::genius_square::models::solver::models::SearchTreeRecorder;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveOutcome;
+use crate::games::genius_square::models::solver::models::SolveStats;
+use crate::games::genius_square::models::solver::models::SolveStatus;
+use crate::models::tree::base::GenericTree;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Recursively solves by check all possibilities
+#[allow(unused)]
+pub fn solve_brute_force(
+    board: &GameBoard,
+) -> GameBoard {
+    let options = SolveOptions::new().with_progress(true);
+    let outcome = solve_brute_force_with_options(board, &options);
+    return outcome.board.unwrap_or_else(|| board.to_owned());
+}
+
+/// Recursively solves by check all possibilities,
+/// subject to the limits (deadline, node budget, cancellation) in the options.
+///
+/// Pieces already on the board are kept.
+pub fn solve_brute_force_with_options(
+    board: &GameBoard,
+    options: &SolveOptions,
+) -> SolveOutcome {
+    search(board, &default_pieces(board), options, false, false, SearchTreeRecorder::default()).0
+}
+
+/// Recursively solves by checking all placements of the given pieces
+/// (on a grid of any geometry, see [`GridGeometry`](crate::games::genius_square::models::geometry::models::GridGeometry)),
+/// subject to the limits in the options.
+///
+/// The pieces are placed in the given order and are identified on the board by their kinds.
+#[allow(unused)]
+pub fn solve_pieces_with_options(
+    board: &GameBoard,
+    pieces: &[Piece],
+    options: &SolveOptions,
+) -> SolveOutcome {
+    search(board, pieces, options, false, false, SearchTreeRecorder::default()).0
+}
+
+/// Recursively counts all solutions by checking all possibilities,
+/// subject to the limits (deadline, node budget, cancellation) in the options.
+///
+/// The count is contained in the statistics of the outcome
+/// and is only exact if the search finished.
+/// The board of the outcome is the first solution found.
+pub fn count_brute_force_with_options(
+    board: &GameBoard,
+    options: &SolveOptions,
+) -> SolveOutcome {
+    search(board, &default_pieces(board), options, true, false, SearchTreeRecorder::default()).0
+}
+
+/// Recursively finds all solutions by checking all possibilities
+/// (cf. [`count_brute_force_with_options`]).
+///
+/// The solutions are returned in the order in which they were found.
+pub fn solve_all_brute_force_with_options(
+    board: &GameBoard,
+    options: &SolveOptions,
+) -> (SolveOutcome, Vec<GameBoard>) {
+    let (outcome, all, _) = search(board, &default_pieces(board), options, true, true, SearchTreeRecorder::default());
+    return (outcome, all);
+}
+
+/// Recursively solves by checking all possibilities (cf. [`solve_brute_force_with_options`])
+/// and records the explored search tree.
+///
+/// Each node of the tree is a placement tried during the search
+/// and records whether a solution was found below it.
+/// Placements deeper than the given depth are not recorded,
+/// but counted in their recorded ancestor instead.
+/// Use the depth to keep the tree small, as the search may explore many nodes.
+pub fn solve_brute_force_with_tree(
+    board: &GameBoard,
+    options: &SolveOptions,
+    max_depth: Option<usize>,
+) -> (SolveOutcome, GenericTree<SearchNode>) {
+    let recorder = SearchTreeRecorder::new(max_depth);
+    let (outcome, _, tree) = search(board, &default_pieces(board), options, false, false, recorder);
+    return (outcome, tree.unwrap());
+}
+
+/// Recursively counts all solutions with the given pieces
+/// (cf. [`solve_pieces_with_options`] and [`count_brute_force_with_options`]).
+#[allow(unused)]
+pub fn count_pieces_with_options(
+    board: &GameBoard,
+    pieces: &[Piece],
+    options: &SolveOptions,
+) -> SolveOutcome {
+    search(board, pieces, options, true, false, SearchTreeRecorder::default()).0
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+/// The pieces of the game which are not on the board yet, in their initial orientation
+fn default_pieces(board: &GameBoard) -> Vec<Piece> {
+    ENUM_PIECES
+        .iter()
+        .filter(|kind| !board.get_pieces().contains_key(kind))
+        .map(|kind| board.create_piece(kind))
+        .collect()
+}
+
+fn search(
+    board: &GameBoard,
+    pieces: &[Piece],
+    options: &SolveOptions,
+    find_all: bool,
+    collect: bool,
+    tree: SearchTreeRecorder,
+) -> (SolveOutcome, Vec<GameBoard>, Option<GenericTree<SearchNode>>) {
+    let pbar = if options.get_progress() {
+        let pbar = ProgressBar::new(pieces.len() as u64);
+        let style = ProgressStyle::with_template("{spinner:.white} [{elapsed_precise}] [{wide_bar:.white}] {pos}/{len} ({eta_precise})");
+        pbar.set_style(style.unwrap());
+        pbar
+    } else {
+        ProgressBar::hidden()
+    };
+    let t0 = Instant::now();
+    let mut ctx = SearchContext::new(board, pieces, options, pbar, find_all);
+    if collect {
+        ctx.all = Some(vec![]);
+    }
+    ctx.tree = tree;
+
+    let result = ctx.recursion(0);
+    ctx.pbar.finish_and_clear();
+    let stats = SolveStats {
+        nodes: ctx.nodes,
+        solutions: ctx.solutions,
+        elapsed: t0.elapsed(),
+    };
+    if options.get_progress() {
+        println!("...completed in {:.2?}", stats.elapsed);
+    }
+
+    let status = match result {
+        Ok(_) if ctx.first.is_some() => SolveStatus::Solved,
+        Ok(_) => SolveStatus::Exhausted,
+        Err(status) => status,
+    };
+    let board = ctx.first.take();
+    let all = ctx.all.take().unwrap_or_default();
+    let tree = ctx.tree.finish(ctx.solutions > 0, ctx.nodes);
+    return (SolveOutcome {status, board, stats}, all, tree);
+}
+
+/// Placement of a piece as bitmasks
+struct Placement {
+    cells: CellMask,
+    /// the cells and the cells next to them
+    dither: CellMask,
+    piece: Piece,
+}
+
+/// State shared across the recursion.
+///
+/// The search mutates a single state in place (placing and unplacing pieces),
+/// so that no allocations occur during the search, except to record the solutions
+/// (and the search tree, if requested).
+struct SearchContext<'a> {
+    options: &'a SolveOptions,
+    pbar: ProgressBar,
+    /// whether to continue after the first solution
+    find_all: bool,
+    first: Option<GameBoard>,
+    /// all solutions (only if they are collected)
+    all: Option<Vec<GameBoard>>,
+    nodes: u64,
+    solutions: u64,
+    /// the initial board
+    board: &'a GameBoard,
+    /// mask of all cells
+    full: CellMask,
+    /// placements of each piece on the initial board (in the order of the pieces)
+    placements: Vec<Vec<Placement>>,
+    /// whether each piece must not touch other such pieces
+    non_adjacent: Vec<bool>,
+    /// index of the placement chosen at each depth
+    chosen: Vec<usize>,
+    /// blocked and covered cells
+    occupied: CellMask,
+    /// cells covered by placed pieces in `NON_ADJACENT`
+    occupied_non_adjacent: CellMask,
+    /// the search tree (only if it is recorded)
+    tree: SearchTreeRecorder,
+}
+
+impl<'a> SearchContext<'a> {
+    fn new(
+        board: &'a GameBoard,
+        pieces: &[Piece],
+        options: &'a SolveOptions,
+        pbar: ProgressBar,
+        find_all: bool,
+    ) -> Self {
+        let (m, n) = board.get_shape();
+        let full = CellMask::full(m, n);
+
+        // NOTE: the order of the configurations is kept, so that the search visits the same nodes
+        let block = board.get_block();
+        let placements = pieces
+            .iter()
+            .map(|piece0| {
+                board
+                    .get_configurations(piece0, block)
+                    .map(|piece| Placement {
+                        cells: CellMask::from_coords(&piece.to_coords(), m, n),
+                        dither: CellMask::from_coords(&piece.transform_dither().to_coords(), m, n),
+                        piece,
+                    })
+                    .collect()
+            })
+            .collect();
+        let non_adjacent = pieces.iter().map(|piece| NON_ADJACENT.contains(&piece.get_kind())).collect();
+        // pieces already on the board are kept
+        let mut occupied = CellMask::from_coords(&block.to_coords(), m, n);
+        for piece in board.get_pieces().values() {
+            occupied.insert(&CellMask::from_coords(&piece.to_coords(), m, n));
+        }
+
+        return Self {
+            options,
+            pbar,
+            find_all,
+            first: None,
+            all: None,
+            nodes: 0,
+            solutions: 0,
+            board,
+            full,
+            placements,
+            non_adjacent,
+            chosen: vec![0; pieces.len()],
+            occupied,
+            occupied_non_adjacent: CellMask::empty(m, n),
+            tree: SearchTreeRecorder::default(),
+        };
+    }
+
+    /// Solutions are recorded in the context. Returns
+    ///
+    /// - `Ok(true)` if the search is done (first solution found),
+    /// - `Ok(false)` if the search has to continue,
+    /// - `Err(status)` if the search had to be aborted.
+    fn recursion(&mut self, depth: usize) -> std::result::Result<bool, SolveStatus> {
+        if depth == self.placements.len() {
+            // if nothing left to solve, then record pieces, provide everything is filled
+            if self.occupied == self.full {
+                self.solutions += 1;
+                if self.first.is_none() {
+                    self.first = Some(to_board(self.board, &self.placements, &self.chosen));
+                }
+                if let Some(all) = self.all.as_mut() {
+                    all.push(to_board(self.board, &self.placements, &self.chosen));
+                }
+                return Ok(!self.find_all);
+            }
+            return Ok(false);
+        }
+
+        // otherwise go through all permissible moves for next piece and then proceed recursively
+        let non_adjacent = self.non_adjacent[depth];
+        for k in 0..self.placements[depth].len() {
+            let placement = &self.placements[depth][k];
+            // skip collisions and moves which lead to forbidden adjacent pieces
+            if placement.cells.intersects(&self.occupied) {
+                continue;
+            }
+            if non_adjacent && placement.dither.intersects(&self.occupied_non_adjacent) {
+                continue;
+            }
+
+            // abort if any of the limits have been reached
+            if let Some(status) = self.options.check_limits(self.nodes) {
+                return Err(status);
+            }
+            self.nodes += 1;
+            self.pbar.inc(1);
+
+            // open a node of the search tree
+            let record = self.tree.records(depth);
+            let (nodes, solutions) = (self.nodes, self.solutions);
+            if record {
+                self.tree.open(SearchNode::from_piece(&self.placements[depth][k].piece));
+            }
+
+            // place the piece
+            self.chosen[depth] = k;
+            let cells = &self.placements[depth][k].cells;
+            self.occupied.insert(cells);
+            if non_adjacent {
+                self.occupied_non_adjacent.insert(cells);
+            }
+
+            // compute remainder of solution recursively
+            let result = self.recursion(depth + 1);
+
+            // unplace the piece
+            let cells = &self.placements[depth][k].cells;
+            self.occupied.remove(cells);
+            if non_adjacent {
+                self.occupied_non_adjacent.remove(cells);
+            }
+
+            // close the node of the search tree
+            if record {
+                self.tree.close(depth, self.solutions > solutions, self.nodes - nodes);
+            }
+            if result? {
+                return Ok(true);
+            }
+            let k = self.pbar.position();
+            self.pbar.set_position(k.saturating_sub(1));
+        }
+        return Ok(false);
+    }
+}
+
+/// The initial board with the chosen placement (index) of each piece
+fn to_board(board: &GameBoard, placements: &[Vec<Placement>], chosen: &[usize]) -> GameBoard {
+    let mut board = board.clone();
+    for (placements, &k) in placements.iter().zip(chosen.iter()) {
+        let piece = &placements[k].piece;
+        board.add_piece(&piece.get_kind(), piece);
+    }
+    return board;
+}
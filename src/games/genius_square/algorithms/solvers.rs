@@ -0,0 +1,220 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use crate::games::genius_square::algorithms::count::count_memoised_with_options;
+use crate::games::genius_square::algorithms::count::visit_memoised_with_options;
+use crate::games::genius_square::algorithms::propagate::count_propagating_with_options;
+use crate::games::genius_square::algorithms::propagate::solve_all_propagating_with_options;
+use crate::games::genius_square::algorithms::propagate::solve_propagating_with_options;
+use crate::games::genius_square::algorithms::propagate::solve_propagating_with_tree;
+use crate::games::genius_square::algorithms::solve::count_brute_force_with_options;
+use crate::games::genius_square::algorithms::solve::solve_all_brute_force_with_options;
+use crate::games::genius_square::algorithms::solve::solve_brute_force_with_options;
+use crate::games::genius_square::algorithms::solve::solve_brute_force_with_tree;
+use crate::games::genius_square::algorithms::validate::validate_solution;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::solver::models::SearchNode;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveOutcome;
+use crate::games::genius_square::models::solver::models::Solver;
+use crate::games::genius_square::models::solver::models::SolverComparison;
+use crate::models::tree::base::GenericTree;
+
+/// ----------------------------------------------------------------
+/// STRUCTS AND CONSTANTS
+/// ----------------------------------------------------------------
+
+/// Tries the placements of the pieces one piece after another
+/// (see [`solve_brute_force_with_options`]).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BruteForceSolver;
+
+/// Places forced pieces before each branching
+/// (see [`solve_propagating_with_options`]).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PropagatingSolver;
+
+/// Fills the lowest empty cell first and memoises the counts of partial states
+/// (see [`count_memoised_with_options`]).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MemoisedSolver;
+
+/// All available strategies
+pub static SOLVERS: &[&dyn Solver] = &[
+    &BruteForceSolver,
+    &PropagatingSolver,
+    &MemoisedSolver,
+];
+
+/// Name of the strategy used unless another one is chosen
+pub const DEFAULT_SOLVER: &str = "propagate";
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Looks up a strategy in [`SOLVERS`] by its name.
+pub fn get_solver(name: &str) -> Result<&'static dyn Solver, String> {
+    SOLVERS
+        .iter()
+        .find(|solver| solver.name() == name.trim())
+        .copied()
+        .ok_or_else(|| {
+            let names: Vec<&str> = SOLVERS.iter().map(|solver| solver.name()).collect();
+            format!("Unknown solver {name} (expected one of {}).", names.join(", "))
+        })
+}
+
+/// Runs the strategies on the same game state and checks that
+///
+/// - their first solutions are valid,
+/// - they find the same set of solutions, all of which are valid.
+///
+/// Strategies which do not finish within the limits of the options
+/// are reported, but not compared.
+pub fn compare_solvers(
+    board: &GameBoard,
+    solvers: &[&dyn Solver],
+    options: &SolveOptions,
+) -> SolverComparison {
+    let mut comparison = SolverComparison::default();
+    let mut expected: Option<(&'static str, Vec<Vec<String>>)> = None;
+    for solver in solvers {
+        let name = solver.name();
+        let first = solver.solve(board, options);
+        let (outcome, solutions) = solver.solve_all(board, options);
+        comparison.runs.push((name, outcome.clone()));
+        if !first.status.is_finished() || !outcome.status.is_finished() {
+            comparison.disagreements.push(format!("{name}: search {} before finishing.", outcome.status));
+            continue;
+        }
+
+        if let Some(solution) = &first.board {
+            if let Err(errors) = validate_solution(solution) {
+                comparison.disagreements.push(format!("{name}: invalid first solution ({}).", errors.join(" ")));
+            }
+        }
+        if first.status != outcome.status {
+            comparison.disagreements.push(format!("{name}: the search for one solution was {}, but for all {}.", first.status, outcome.status));
+        }
+        let num_invalid = solutions.iter().filter(|solution| validate_solution(solution).is_err()).count();
+        if num_invalid > 0 {
+            comparison.disagreements.push(format!("{name}: {num_invalid} invalid solutions."));
+        }
+
+        let mut rows: Vec<Vec<String>> = solutions.iter().map(|solution| solution.to_rows()).collect();
+        rows.sort();
+        match &expected {
+            None => {
+                expected = Some((name, rows));
+            },
+            Some((name0, rows0)) => {
+                let missing = rows0.iter().filter(|x| rows.binary_search(x).is_err()).count();
+                let extra = rows.iter().filter(|x| rows0.binary_search(x).is_err()).count();
+                if rows.len() != rows0.len() || missing > 0 || extra > 0 {
+                    comparison.disagreements.push(format!(
+                        "{name}: {} solutions, {missing} of the {} solutions of {name0} missing, {extra} others.",
+                        rows.len(),
+                        rows0.len(),
+                    ));
+                }
+            },
+        }
+    }
+    return comparison;
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl Solver for BruteForceSolver {
+    fn name(&self) -> &'static str {
+        "brute-force"
+    }
+
+    fn description(&self) -> &'static str {
+        "tries the placements of the pieces one piece after another"
+    }
+
+    fn solve(&self, board: &GameBoard, options: &SolveOptions) -> SolveOutcome {
+        solve_brute_force_with_options(board, options)
+    }
+
+    fn solve_all(&self, board: &GameBoard, options: &SolveOptions) -> (SolveOutcome, Vec<GameBoard>) {
+        solve_all_brute_force_with_options(board, options)
+    }
+
+    fn count(&self, board: &GameBoard, options: &SolveOptions) -> SolveOutcome {
+        count_brute_force_with_options(board, options)
+    }
+
+    fn solve_with_tree(
+        &self,
+        board: &GameBoard,
+        options: &SolveOptions,
+        max_depth: Option<usize>,
+    ) -> Result<(SolveOutcome, GenericTree<SearchNode>), String> {
+        Ok(solve_brute_force_with_tree(board, options, max_depth))
+    }
+}
+
+impl Solver for PropagatingSolver {
+    fn name(&self) -> &'static str {
+        "propagate"
+    }
+
+    fn description(&self) -> &'static str {
+        "places forced pieces first and branches on the piece or cell with the fewest options"
+    }
+
+    fn solve(&self, board: &GameBoard, options: &SolveOptions) -> SolveOutcome {
+        solve_propagating_with_options(board, options)
+    }
+
+    fn solve_all(&self, board: &GameBoard, options: &SolveOptions) -> (SolveOutcome, Vec<GameBoard>) {
+        solve_all_propagating_with_options(board, options)
+    }
+
+    fn count(&self, board: &GameBoard, options: &SolveOptions) -> SolveOutcome {
+        count_propagating_with_options(board, options)
+    }
+
+    fn solve_with_tree(
+        &self,
+        board: &GameBoard,
+        options: &SolveOptions,
+        max_depth: Option<usize>,
+    ) -> Result<(SolveOutcome, GenericTree<SearchNode>), String> {
+        Ok(solve_propagating_with_tree(board, options, max_depth))
+    }
+}
+
+/// Counting is required to find a solution,
+/// so that the outcome of [`Solver::solve`] contains the number of all solutions.
+impl Solver for MemoisedSolver {
+    fn name(&self) -> &'static str {
+        "memoised"
+    }
+
+    fn description(&self) -> &'static str {
+        "fills the lowest empty cell first and memoises the counts of partial states"
+    }
+
+    fn solve(&self, board: &GameBoard, options: &SolveOptions) -> SolveOutcome {
+        count_memoised_with_options(board, options)
+    }
+
+    fn solve_all(&self, board: &GameBoard, options: &SolveOptions) -> (SolveOutcome, Vec<GameBoard>) {
+        let mut solutions: Vec<GameBoard> = vec![];
+        let outcome = visit_memoised_with_options(board, options, |solution| {
+            solutions.push(solution.clone());
+        });
+        return (outcome, solutions);
+    }
+
+    fn count(&self, board: &GameBoard, options: &SolveOptions) -> SolveOutcome {
+        count_memoised_with_options(board, options)
+    }
+}
@@ -0,0 +1,83 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::collections::HashSet;
+
+use super::cnf::*;
+use super::validate::validate_solution;
+use crate::games::genius_square::models::board::methods::board_from_placements;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::tests_support::SOLUTION;
+use crate::games::genius_square::tests_support::create_board;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dimacs_header() {
+        let encoding = encode_cnf(&create_board(""));
+        let formula = encoding.get_formula();
+        let dimacs = encoding.to_dimacs();
+        let header = format!("p cnf {} {}", formula.get_num_vars(), formula.get_clauses().len());
+        assert!(formula.get_num_vars() > 0);
+        assert!(dimacs.lines().any(|line| line == header));
+        assert!(dimacs.lines().filter(|line| !line.starts_with(['c', 'p'])).all(|line| line.ends_with(" 0")));
+    }
+
+    #[test]
+    fn test_known_solution_is_model() {
+        let board = create_board("");
+        let encoding = encode_cnf(&board);
+        let solution = create_board(SOLUTION);
+        let true_vars: HashSet<i64> = solution
+            .get_pieces()
+            .values()
+            .map(|piece| encoding.find_var(piece).unwrap())
+            .collect();
+        assert_eq!(true_vars.len(), 9);
+        assert!(encoding.get_formula().is_satisfied_by(&true_vars));
+
+        // round trip through the output format of a SAT solver
+        let literals: Vec<String> = (1..=encoding.get_formula().get_num_vars() as i64)
+            .map(|var| if true_vars.contains(&var) { var.to_string() } else { (-var).to_string() })
+            .collect();
+        let model = format!("c some solver\ns SATISFIABLE\nv {} 0\n", literals.join(" "));
+        let decoded = encoding.decode_model(&model).unwrap();
+        assert_eq!(validate_solution(&decoded), Ok(()));
+        assert_eq!(decoded.to_rows(), solution.to_rows());
+    }
+
+    #[test]
+    fn test_touching_pieces_excluded() {
+        let board = create_board("");
+        let encoding = encode_cnf(&board);
+        let partial = board_from_placements("1=A1; 2=A2,A3; 4=B2,C2,D2,E2", board.get_block()).unwrap();
+        let var = |symb: &str| {
+            let kind = EnumPiece::from_symb(symb).unwrap();
+            encoding.find_var(&partial.get_pieces()[&kind]).unwrap()
+        };
+        let clauses = encoding.get_formula().get_clauses();
+        // 1 and 2 must not touch
+        assert!(clauses.contains(&vec![-var("1"), -var("2")]));
+        // 4 may touch 2
+        assert!(!clauses.contains(&vec![-var("2"), -var("4")]));
+    }
+
+    #[test]
+    fn test_unsatisfiable_model() {
+        let encoding = encode_cnf(&create_board(""));
+        assert!(encoding.decode_model("s UNSATISFIABLE\n").is_err());
+        assert!(encoding.decode_model("v 1 x 0\n").is_err());
+        // solvers which gave up and partial models
+        assert!(encoding.decode_model("s UNKNOWN\n").is_err());
+        assert!(encoding.decode_model("s INDETERMINATE\nv 1 0\n").is_err());
+        assert!(encoding.decode_model("s SATISFIABLE\nv 0\n").unwrap_err().contains("not placed"));
+    }
+}
@@ -0,0 +1,132 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use rstest::rstest;
+use std::collections::HashMap;
+
+use super::count::*;
+use super::solve::count_pieces_with_options;
+use super::validate::validate_solution;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::_core::rand::seed_rng;
+use crate::games::genius_square::models::constants::enums::ENUM_PIECES;
+use crate::games::genius_square::models::pieces::models::Piece;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveStatus;
+use crate::games::genius_square::tests_support::create_board;
+use crate::games::genius_square::tests_support::embed_board;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest]
+    #[case("Z=E1,D2,E2,D3; L=A2,A3,A4,B4; X=B2,C2,B3,C3; T=E3,D4,E4,F4")]
+    #[case("Z=E1,D2,E2,D3; X=B2,C2,B3,C3; 4=A5,B5,C5,D5")]
+    #[case("L=A2,A3,A4,B4; X=B2,C2,B3,C3; T=E3,D4,E4,F4")]
+    fn test_agrees_with_brute_force(#[case] placements: &str) {
+        let board = create_board(placements);
+        let outcome = count_memoised_with_options(&board, &SolveOptions::new());
+
+        // the brute force search places all given pieces, so block the cells of the placed ones
+        // (none of which may be in NON_ADJACENT)
+        let mut block = board.get_block().to_owned();
+        for piece in board.get_pieces().values() {
+            block = block + piece.clone();
+        }
+        let pieces: Vec<Piece> = ENUM_PIECES
+            .iter()
+            .filter(|kind| !board.get_pieces().contains_key(kind))
+            .map(|kind| Piece::from_kind(kind, None))
+            .collect();
+        let expected = count_pieces_with_options(&GameBoard::new(&block), &pieces, &SolveOptions::new());
+
+        assert_eq!(outcome.stats.solutions, expected.stats.solutions);
+        assert_eq!(outcome.status, expected.status);
+        if let Some(solution) = outcome.board {
+            assert_eq!(validate_solution(&solution), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_full_roll() {
+        let board = create_board("");
+        let outcome = count_memoised_with_options(&board, &SolveOptions::new());
+        assert_eq!(outcome.status, SolveStatus::Solved);
+        assert!(outcome.stats.solutions > 1);
+        assert_eq!(validate_solution(&outcome.board.unwrap()), Ok(()));
+    }
+
+    #[test]
+    fn test_board_size() {
+        let board = create_board("X=B2,C2,B3,C3");
+        let expected = count_memoised_with_options(&board, &SolveOptions::new());
+        // 64 cells fit into the masks
+        let outcome = count_memoised_with_options(&embed_board(&board, 8, 8), &SolveOptions::new());
+        assert_eq!(outcome.status, SolveStatus::Solved);
+        assert_eq!(outcome.stats.solutions, expected.stats.solutions);
+        // larger boards are rejected instead of panicking
+        let board = embed_board(&board, 12, 12);
+        let outcome = count_memoised_with_options(&board, &SolveOptions::new());
+        assert_eq!((outcome.status, outcome.stats.solutions), (SolveStatus::Unsupported, 0));
+        let mut rng = seed_rng(Some("1234".to_string()));
+        let (outcome, samples) = sample_many_memoised_with_options(&board, &SolveOptions::new(), &mut rng, 3);
+        assert_eq!(outcome.status, SolveStatus::Unsupported);
+        assert!(samples.is_empty());
+    }
+
+    #[test]
+    fn test_node_limit() {
+        let board = create_board("");
+        let options = SolveOptions::new().with_max_nodes(5);
+        let outcome = count_memoised_with_options(&board, &options);
+        assert_eq!(outcome.status, SolveStatus::NodeLimit);
+        assert_eq!(outcome.stats.solutions, 0);
+        assert!(outcome.board.is_none());
+    }
+
+    #[test]
+    fn test_sample_uniformly() {
+        let board = create_board("L=A2,A3,A4,B4; T=E3,D4,E4,F4");
+        let mut frequencies: HashMap<Vec<String>, usize> = HashMap::new();
+        visit_memoised_with_options(&board, &SolveOptions::new(), |solution| {
+            frequencies.insert(solution.to_rows(), 0);
+        });
+        let num_solutions = frequencies.len();
+        assert!((2..=100).contains(&num_solutions), "{num_solutions}");
+
+        // every solution is drawn about equally often
+        let expected = 200;
+        let mut rng = seed_rng(Some("sample".to_string()));
+        let (outcome, samples) = sample_many_memoised_with_options(&board, &SolveOptions::new(), &mut rng, expected * num_solutions);
+        assert_eq!(outcome.stats.solutions as usize, num_solutions);
+        for sample in samples.iter() {
+            *frequencies.get_mut(&sample.to_rows()).unwrap() += 1;
+        }
+        for (rows, &frequency) in frequencies.iter() {
+            assert!(frequency.abs_diff(expected) < expected / 2, "{rows:?} drawn {frequency} times");
+        }
+    }
+
+    #[test]
+    fn test_sample_reproducible() {
+        let board = create_board("");
+        let sample = |seed: &str| {
+            let mut rng = seed_rng(Some(seed.to_string()));
+            let outcome = sample_memoised_with_options(&board, &SolveOptions::new(), &mut rng);
+            assert_eq!(outcome.status, SolveStatus::Solved);
+            let solution = outcome.board.unwrap();
+            assert_eq!(validate_solution(&solution), Ok(()));
+            return solution.to_rows();
+        };
+        assert_eq!(sample("a"), sample("a"));
+        let distinct: std::collections::HashSet<Vec<String>> = ["a", "b", "c", "d"].iter().map(|seed| sample(seed)).collect();
+        assert!(distinct.len() > 1);
+    }
+}
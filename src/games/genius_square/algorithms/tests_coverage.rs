@@ -0,0 +1,67 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use super::count::count_memoised_with_options;
+use super::coverage::*;
+use crate::games::genius_square::models::constants::enums::ENUM_PIECES;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveStatus;
+use crate::games::genius_square::tests_support::create_board;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_cell_covered_once() {
+        let board = create_board("");
+        let coverage = compute_coverage_with_options(&board, &SolveOptions::new()).unwrap();
+        let expected = count_memoised_with_options(&board, &SolveOptions::new());
+        assert_eq!(coverage.get_num_solutions(), expected.stats.solutions);
+
+        let blocked = board.get_block().to_coords();
+        for i in 0..6 {
+            for j in 0..6 {
+                let total: u64 = ENUM_PIECES.iter().map(|kind| coverage.get_count(kind, (i, j))).sum();
+                let expected = if blocked.contains(&(i, j)) { 0 } else { coverage.get_num_solutions() };
+                assert_eq!(total, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_forced_placements() {
+        // with the 1, 2 and 3 to place, the L can only go into the bottom left corner
+        let board = create_board("Z=E1,D2,E2,D3; X=B2,C2,B3,C3; T=E3,D4,E4,F4; 4=A5,B5,C5,D5; C=E5,E6,F6");
+        let coverage = compute_coverage_with_options(&board, &SolveOptions::new()).unwrap();
+        assert!(coverage.get_num_solutions() > 0);
+        let forced = coverage.get_forced_placements();
+        let cells_l = forced.iter().find(|(kind, _)| *kind == EnumPiece::L).map(|(_, cells)| cells.clone());
+        assert_eq!(cells_l, Some(vec!["A2".to_string(), "A3".to_string(), "A4".to_string(), "B4".to_string()]));
+        for (kind, cells) in forced.iter() {
+            assert!(!board.get_pieces().contains_key(kind));
+            assert!(!cells.is_empty());
+        }
+
+        let json = coverage.to_json();
+        assert_eq!(json["solutions"], coverage.get_num_solutions());
+        assert_eq!(json["forced"]["L"][0], "A2");
+        assert_eq!(json["cells"]["A2"]["L"], coverage.get_num_solutions());
+        assert!(json["cells"].get("B2").is_none());
+    }
+
+    #[test]
+    fn test_node_limit() {
+        let board = create_board("");
+        let options = SolveOptions::new().with_max_nodes(5);
+        let result = compute_coverage_with_options(&board, &options);
+        assert_eq!(result.err(), Some(SolveStatus::NodeLimit));
+    }
+}
@@ -0,0 +1,66 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use rstest::rstest;
+
+use super::dice::*;
+use crate::games::genius_square::models::dice::methods::all_rolls;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legal_layout() {
+        let result = match_layout(&to_cells("C4 B1 D6 F1 F2 F3 F5")).unwrap();
+        assert!(result.is_legal());
+        assert_eq!(result.assignments, vec![to_cells("F2 F1 F5 B1 F3 C4 D6")]);
+        // F2 is on two faces and F1 on three faces of their dice
+        let expected = (2. / 6.) * (3. / 6.) * (1. / 6_f64).powi(5);
+        assert!((result.probability - expected).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_illegal_layout() {
+        // A6 and F1 are on the same die
+        let result = match_layout(&to_cells("A6 F1 D6 B1 F2 F3 F5")).unwrap();
+        assert!(!result.is_legal());
+        assert_eq!(result.probability, 0.);
+    }
+
+    #[test]
+    fn test_several_assignments() {
+        let dice: &[&[&str]] = &[&["A1", "B1"], &["A1", "B1", "C1"], &["B1", "C1", "C1"]];
+        let result = match_layout_with_dice(&to_cells("A1 B1 C1"), dice).unwrap();
+        assert_eq!(result.assignments, vec![to_cells("A1 B1 C1"), to_cells("A1 C1 B1"), to_cells("B1 A1 C1")]);
+        let expected = (1. / 2.) * (1. / 3.) * (2. / 3.) + (1. / 2.) * (1. / 3.) * (1. / 3.) + (1. / 2.) * (1. / 3.) * (2. / 3.);
+        assert!((result.probability - expected).abs() < 1e-15);
+    }
+
+    #[rstest]
+    #[case("B1 C4 D6 F1 F2 F3")]
+    #[case("B1 B1 D6 F1 F2 F3 F5")]
+    #[case("B1 C4 D6 F1 F2 F3 G7")]
+    fn test_invalid_layout(#[case] cells: &str) {
+        assert!(match_layout(&to_cells(cells)).is_err());
+    }
+
+    #[test]
+    fn test_probabilities_sum_to_one() {
+        let total: f64 = all_rolls()
+            .iter()
+            .map(|faces| match_layout(faces).unwrap().probability)
+            .sum();
+        assert!((total - 1.).abs() < 1e-9);
+    }
+
+    fn to_cells(text: &str) -> Vec<String> {
+        text.split_whitespace().map(|cell| cell.to_string()).collect()
+    }
+}
@@ -0,0 +1,63 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use super::count::count_memoised_with_options;
+use super::count::visit_memoised_with_options;
+use super::diverse::*;
+use super::validate::validate_solution;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveStatus;
+use crate::games::genius_square::tests_support::create_board;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diverse_solutions() {
+        let board = create_board("L=A2,A3,A4,B4");
+        let num_solutions = count_memoised_with_options(&board, &SolveOptions::new()).stats.solutions;
+        assert!(num_solutions > 4);
+
+        let solutions = find_diverse_with_options(&board, &SolveOptions::new(), 4).unwrap();
+        assert_eq!(solutions.len(), 4);
+        let mut min_distance = usize::MAX;
+        for (k, solution) in solutions.iter().enumerate() {
+            assert!(validate_solution(solution).is_ok());
+            for other in solutions[..k].iter() {
+                min_distance = min_distance.min(solution_distance(solution, other));
+            }
+        }
+        // the fixed L and the blocked cells never differ
+        assert!(min_distance > 0 && min_distance <= 36 - 7 - 4);
+
+        // no solution is further from either solution of the chosen pair
+        let pair = find_diverse_with_options(&board, &SolveOptions::new(), 2).unwrap();
+        let mut all: Vec<GameBoard> = vec![];
+        visit_memoised_with_options(&board, &SolveOptions::new(), |solution| all.push(solution.clone()));
+        let distance = solution_distance(&pair[0], &pair[1]);
+        for solution in all.iter() {
+            assert!(solution_distance(&pair[0], solution) <= distance);
+            assert!(solution_distance(&pair[1], solution) <= distance);
+        }
+    }
+
+    #[test]
+    fn test_fewer_solutions_than_requested() {
+        let board = create_board("L=A2,A3,A4,B4; T=E3,D4,E4,F4");
+        let num_solutions = count_memoised_with_options(&board, &SolveOptions::new()).stats.solutions;
+        let solutions = find_diverse_with_options(&board, &SolveOptions::new(), 100).unwrap();
+        assert_eq!(solutions.len() as u64, num_solutions);
+        assert!(find_diverse_with_options(&board, &SolveOptions::new(), 0).unwrap().is_empty());
+
+        let options = SolveOptions::new().with_max_nodes(5);
+        assert_eq!(find_diverse_with_options(&create_board(""), &options, 3).err(), Some(SolveStatus::NodeLimit));
+    }
+}
@@ -0,0 +1,77 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use super::count::count_memoised_with_options;
+use super::propagate::*;
+use super::validate::validate_solution;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::deduction::models::Contradiction;
+use crate::games::genius_square::models::deduction::models::DeductionReason;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveStatus;
+use crate::games::genius_square::tests_support::create_board;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deduce_forced_placements() {
+        // with the 1, 2 and 3 to place, the L can only go into the bottom left corner
+        let board = create_board("Z=E1,D2,E2,D3; X=B2,C2,B3,C3; T=E3,D4,E4,F4; 4=A5,B5,C5,D5; C=E5,E6,F6");
+        let (solution, deductions) = deduce(&board).unwrap();
+        assert!(!deductions.is_empty());
+        let l = deductions.iter().find(|deduction| deduction.piece.get_kind() == EnumPiece::L).unwrap();
+        assert_eq!(l.get_cells(), vec!["A2", "A3", "A4", "B4"]);
+        for deduction in deductions.iter() {
+            if let DeductionReason::OnlyCover(cell) = deduction.reason {
+                assert!(deduction.piece.to_coords().contains(&cell));
+            }
+            assert!(!deduction.to_string().is_empty());
+        }
+        // the solution is unique, so everything follows logically
+        assert_eq!(count_memoised_with_options(&board, &SolveOptions::new()).stats.solutions, 1);
+        assert!(validate_solution(&solution).is_ok());
+    }
+
+    #[test]
+    fn test_deduce_contradiction() {
+        // the 1 at F6 leaves no room for the 2
+        let board = create_board("1=F6; X=B2,C2,B3,C3; Z=E1,D2,E2,D3; L=A2,A3,A4,B4; T=E3,D4,E4,F4; 4=A5,B5,C5,D5; 3=A6,B6,C6");
+        let result = deduce(&board);
+        assert!(result.is_err());
+        assert!(matches!(result.err().unwrap(), Contradiction::UncoverableCell(_) | Contradiction::UnplaceablePiece(_)));
+        let outcome = solve_propagating_with_options(&board, &SolveOptions::new());
+        assert_eq!(outcome.status, SolveStatus::Exhausted);
+    }
+
+    #[test]
+    fn test_count_agrees_with_memoised() {
+        for placements in ["", "L=A2,A3,A4,B4", "X=D1,E1,D2,E2"] {
+            let board = create_board(placements);
+            let expected = count_memoised_with_options(&board, &SolveOptions::new());
+            let outcome = count_propagating_with_options(&board, &SolveOptions::new());
+            assert_eq!(outcome.status, expected.status);
+            assert_eq!(outcome.stats.solutions, expected.stats.solutions, "{placements}");
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let board = create_board("");
+        let outcome = solve_propagating_with_options(&board, &SolveOptions::new());
+        assert_eq!(outcome.status, SolveStatus::Solved);
+        assert!(validate_solution(outcome.board.as_ref().unwrap()).is_ok());
+
+        let options = SolveOptions::new().with_max_nodes(5);
+        let outcome = solve_propagating_with_options(&board, &options);
+        assert_eq!(outcome.status, SolveStatus::NodeLimit);
+        assert_eq!(outcome.stats.nodes, 5);
+    }
+}
@@ -0,0 +1,167 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use super::solve::*;
+use super::validate::validate_solution;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::geometry::models::SQUARE_GRID;
+use crate::games::genius_square::models::pieces::models::Piece;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SearchNode;
+use crate::games::genius_square::models::solver::models::SolveStatus;
+use crate::games::genius_square::tests_support::create_board;
+use crate::games::genius_square::tests_support::embed_board;
+use crate::models::tree::base::GenericTree;
+use crate::models::tree::base::GenericTreeLike;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solved() {
+        let board = create_board("");
+        let outcome = solve_brute_force_with_options(&board, &SolveOptions::new());
+        assert_eq!(outcome.status, SolveStatus::Solved);
+        assert!(outcome.board.is_some());
+        assert!(outcome.stats.nodes > 0);
+    }
+
+    #[test]
+    fn test_search_order() {
+        // the placements are tried in the order of the configurations
+        let board = create_board("");
+        let outcome = solve_brute_force_with_options(&board, &SolveOptions::new());
+        assert_eq!(outcome.stats.nodes, 9802);
+        let rows = vec!["1■22Z■", "LXXZZ■", "LXXZT■", "LL■TTT", "4444C■", "333■CC"];
+        assert_eq!(outcome.board.unwrap().to_rows(), rows);
+    }
+
+    #[test]
+    fn test_partly_filled_board() {
+        // the pieces on the board are kept
+        let board = create_board("X=B2,C2,B3,C3");
+        let outcome = solve_brute_force_with_options(&board, &SolveOptions::new());
+        assert_eq!(outcome.status, SolveStatus::Solved);
+        let solution = outcome.board.unwrap();
+        assert!(validate_solution(&solution).is_ok());
+        assert_eq!(solution.to_rows()[1..3], ["LXXZZ■", "LXXZT■"]);
+    }
+
+    #[test]
+    fn test_large_board() {
+        // a 12 x 12 board, on which only the last cells of the last row are free
+        let (m, n) = (12, 12);
+        let blocked = (0..m * n - 4).map(|k| (k / n, k % n)).collect();
+        let block = Piece::from_coords_on(&SQUARE_GRID, blocked, m, n, Some(EnumPiece::Block));
+        let pieces = vec![
+            Piece::from_coords_on(&SQUARE_GRID, vec![(0, 0), (1, 0), (2, 0), (3, 0)], m, n, Some(EnumPiece::Symb4)),
+        ];
+        let outcome = solve_pieces_with_options(&GameBoard::new(&block), &pieces, &SolveOptions::new());
+        assert_eq!(outcome.status, SolveStatus::Solved);
+        assert_eq!(outcome.board.unwrap().to_rows()[m - 1], "■■■■■■■■4444");
+    }
+
+    #[test]
+    fn test_embedded_board() {
+        // the default pieces are placed on boards of any size
+        let board = create_board("");
+        let outcome = solve_brute_force_with_options(&board, &SolveOptions::new());
+        let outcome_large = solve_brute_force_with_options(&embed_board(&board, 12, 12), &SolveOptions::new());
+        assert_eq!(outcome_large.status, SolveStatus::Solved);
+        assert_eq!(outcome_large.stats.nodes, outcome.stats.nodes);
+        let rows = outcome.board.unwrap().to_rows();
+        let rows_large = outcome_large.board.unwrap().to_rows();
+        for (row, row_large) in rows.iter().zip(rows_large[6..].iter()) {
+            assert_eq!(format!("■■■■■■{row}"), *row_large);
+        }
+    }
+
+    #[test]
+    fn test_search_tree() {
+        let board = create_board("");
+        let (outcome, tree) = solve_brute_force_with_tree(&board, &SolveOptions::new(), Some(3));
+        assert_eq!(outcome.stats.nodes, 9802);
+        assert!(tree.root.success);
+        // every node is either recorded or counted in a recorded ancestor
+        assert_eq!(count_nodes(&tree), 9802);
+        // the search stops in the first successful branch
+        let mut node = &tree;
+        while let Some((last, others)) = node.children.split_last() {
+            assert!(last.root.success && others.iter().all(|child| !child.root.success));
+            node = last;
+        }
+        assert_eq!(get_depth(&tree), 3);
+        let lines = tree.repr_tree(None, None, None);
+        assert_eq!(lines.len(), 11);
+        assert_eq!(lines[0], "start ✓");
+        assert!(lines[1].ends_with("1=A1 ✓"));
+        assert!(lines[10].ends_with("3=A6,B6,C6 ✓ (+2512 nodes)"));
+
+        // without a limit, the tree records every node
+        let (outcome, tree) = solve_brute_force_with_tree(&board, &SolveOptions::new(), None);
+        assert_eq!(count_nodes(&tree), outcome.stats.nodes);
+        assert!(tree.children.iter().all(|child| child.root.unrecorded == 0));
+    }
+
+    #[test]
+    fn test_node_limit() {
+        let board = create_board("");
+        let options = SolveOptions::new().with_max_nodes(5);
+        let outcome = solve_brute_force_with_options(&board, &options);
+        assert_eq!(outcome.status, SolveStatus::NodeLimit);
+        assert!(outcome.board.is_none());
+        assert_eq!(outcome.stats.nodes, 5);
+    }
+
+    #[test]
+    fn test_timeout() {
+        let board = create_board("");
+        let options = SolveOptions::new().with_timeout(Duration::ZERO);
+        let outcome = solve_brute_force_with_options(&board, &options);
+        assert_eq!(outcome.status, SolveStatus::TimedOut);
+        // a timeout beyond any representable instant imposes no deadline
+        let options = SolveOptions::new().with_timeout(Duration::MAX);
+        let outcome = solve_brute_force_with_options(&board, &options);
+        assert_eq!(outcome.status, SolveStatus::Solved);
+    }
+
+    #[test]
+    fn test_cancelled() {
+        let board = create_board("");
+        let cancel = Arc::new(AtomicBool::new(true));
+        let options = SolveOptions::new().with_cancel(cancel);
+        let outcome = solve_brute_force_with_options(&board, &options);
+        assert_eq!(outcome.status, SolveStatus::Cancelled);
+        assert_eq!(outcome.stats.nodes, 0);
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Number of nodes in the search tree (recorded or not), except for the root
+fn count_nodes(tree: &GenericTree<SearchNode>) -> u64 {
+    return tree
+        .children
+        .iter()
+        .map(|child| 1 + child.root.unrecorded + count_nodes(child))
+        .sum();
+}
+
+/// Length of the longest branch of the search tree
+fn get_depth(tree: &GenericTree<SearchNode>) -> usize {
+    return tree.children.iter().map(|child| 1 + get_depth(child)).max().unwrap_or(0);
+}
@@ -0,0 +1,111 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::collections::HashSet;
+
+use super::solvers::*;
+use super::validate::validate_solution;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveStatus;
+use crate::games::genius_square::models::geometry::models::GridGeometry;
+use crate::games::genius_square::models::geometry::models::SQUARE_GRID;
+use crate::games::genius_square::models::geometry::models::TRI_GRID;
+use crate::games::genius_square::models::pieces::models::Piece;
+use crate::games::genius_square::models::solver::models::SearchNode;
+use crate::games::genius_square::tests_support::create_board;
+use crate::games::genius_square::tests_support::embed_board;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        assert!(get_solver(DEFAULT_SOLVER).is_ok());
+        let names: Vec<&str> = SOLVERS.iter().map(|solver| solver.name()).collect();
+        for name in names.iter() {
+            assert_eq!(get_solver(name).unwrap().name(), *name);
+        }
+        let unique: HashSet<&str> = names.iter().copied().collect();
+        assert_eq!(unique.len(), names.len());
+        assert!(get_solver("guess").unwrap_err().contains("brute-force"));
+    }
+
+    #[test]
+    fn test_solvers_agree() {
+        let board = create_board("L=A2,A3,A4,B4");
+        let comparison = compare_solvers(&board, SOLVERS, &SolveOptions::new());
+        assert!(comparison.agrees(), "{:?}", comparison.disagreements);
+        assert_eq!(comparison.runs.len(), SOLVERS.len());
+        let count = comparison.runs[0].1.stats.solutions;
+        assert!(count > 1);
+        for solver in SOLVERS {
+            assert_eq!(solver.count(&board, &SolveOptions::new()).stats.solutions, count, "{}", solver.name());
+            let outcome = solver.solve(&board, &SolveOptions::new());
+            assert_eq!(outcome.status, SolveStatus::Solved);
+            assert!(validate_solution(&outcome.board.unwrap()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_search_trees() {
+        let board = create_board("L=A2,A3,A4,B4");
+        for solver in SOLVERS {
+            let Ok((outcome, tree)) = solver.solve_with_tree(&board, &SolveOptions::new(), Some(1)) else {
+                assert_eq!(solver.name(), "memoised");
+                continue;
+            };
+            assert_eq!(outcome.status, SolveStatus::Solved);
+            assert!(tree.root.success && tree.children.last().unwrap().root.success);
+            // the nodes below the first level are counted in the first level
+            let nodes: u64 = tree.children.iter().map(|child| 1 + child.root.unrecorded).sum();
+            assert_eq!(nodes, outcome.stats.nodes, "{}", solver.name());
+            let (_, tree) = solver.solve_with_tree(&board, &SolveOptions::new(), Some(0)).unwrap();
+            assert!(!tree.has_children());
+            assert_eq!(tree.root.unrecorded, outcome.stats.nodes);
+        }
+    }
+
+    #[test]
+    fn test_search_trees_on_large_board() {
+        let board = create_board("L=A2,A3,A4,B4");
+        let board_large = embed_board(&board, 12, 12);
+        for solver in SOLVERS {
+            let Ok((outcome, tree)) = solver.solve_with_tree(&board_large, &SolveOptions::new(), Some(1)) else {
+                continue;
+            };
+            assert_eq!(outcome.status, SolveStatus::Solved);
+            let (outcome_small, _) = solver.solve_with_tree(&board, &SolveOptions::new(), Some(1)).unwrap();
+            assert_eq!(outcome.stats.nodes, outcome_small.stats.nodes, "{}", solver.name());
+            // the cells are labelled on the large board, i.e. in the bottom right corner
+            for child in tree.children.iter() {
+                for label in child.root.cells.iter() {
+                    let (i, j) = SQUARE_GRID.parse_label(label).unwrap();
+                    assert!(6 <= i && i < 12 && 6 <= j && j < 12, "{label}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_search_node_labels() {
+        // cells are labelled by the geometry of the grid
+        let piece = Piece::from_coords_on(&TRI_GRID, vec![(1, 2), (1, 3)], 4, 8, None);
+        assert_eq!(SearchNode::from_piece(&piece).cells, vec!["B2^", "B2v"]);
+    }
+
+    #[test]
+    fn test_unfinished_runs_are_reported() {
+        let board = create_board("");
+        let options = SolveOptions::new().with_max_nodes(3);
+        let comparison = compare_solvers(&board, &[get_solver("brute-force").unwrap()], &options);
+        assert!(!comparison.agrees());
+        assert_eq!(comparison.runs[0].1.status, SolveStatus::NodeLimit);
+    }
+}
@@ -0,0 +1,68 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use indoc::indoc;
+
+use super::validate::*;
+use crate::games::genius_square::models::board::methods::board_from_grid;
+use crate::games::genius_square::tests_support::SOLUTION;
+use crate::games::genius_square::tests_support::create_board;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_grid() {
+        let block = create_board("").get_block().clone();
+        let board = board_from_grid(GRID, &block).unwrap();
+        assert_eq!(validate_solution(&board), Ok(()));
+    }
+
+    #[test]
+    fn test_valid_placements() {
+        let board = create_board(SOLUTION);
+        assert_eq!(validate_solution(&board), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_grid() {
+        let block = create_board("").get_block().clone();
+        // swap the 1 and the right-most cell of the 3-piece
+        let text = GRID.replacen("1", "3", 1).replacen("3■C", "1■C", 1);
+        let board = board_from_grid(&text, &block).unwrap();
+        let errors = validate_solution(&board).unwrap_err();
+        assert_eq!(errors, vec![
+            "Piece 3 has the wrong shape.".to_string(),
+            "Pieces 1 and 3 touch.".to_string(),
+        ]);
+    }
+
+    #[test]
+    fn test_incomplete() {
+        let board = create_board("1=A1; 2=C1,D1");
+        let errors = validate_solution(&board).unwrap_err();
+        assert!(errors.contains(&"Piece X is missing.".to_string()));
+        assert!(errors.contains(&"26 cell(s) are not covered.".to_string()));
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// [`SOLUTION`] as a grid
+const GRID: &str = indoc! {"
+    1■22Z■
+    LXXZZ■
+    LXXZT■
+    LL■TTT
+    4444C■
+    333■CC
+"};
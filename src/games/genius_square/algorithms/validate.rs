@@ -0,0 +1,83 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use crate::games::genius_square::models::arrays::models::BinArray;
+use crate::games::genius_square::models::constants::enums::ENUM_PIECES;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::constants::enums::NON_ADJACENT;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::pieces::catalogue::PieceInfo;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Checks whether a board is a complete and legal solution, viz.
+///
+/// - every piece is placed exactly once and has the correct shape,
+/// - no piece collides with the block or another piece,
+/// - every cell is covered,
+/// - none of the pieces in [`NON_ADJACENT`] touch each other.
+///
+/// Returns the list of all violations, if any.
+pub fn validate_solution(
+    board: &GameBoard,
+) -> Result<(), Vec<String>> {
+    let mut errors: Vec<String> = vec![];
+    let pieces = board.get_pieces();
+
+    // check pieces
+    for kind in ENUM_PIECES {
+        match pieces.get(kind) {
+            Some(piece) => {
+                if !is_orientation_of(piece.get_positions(), kind) {
+                    errors.push(format!("Piece {} has the wrong shape.", kind.as_str()));
+                }
+            },
+            None => {
+                errors.push(format!("Piece {} is missing.", kind.as_str()));
+            },
+        }
+    }
+
+    // check collisions and coverage
+    let mut occupied = board.get_block().get_positions().to_owned();
+    for kind in ENUM_PIECES {
+        if let Some(piece) = pieces.get(kind) {
+            if occupied.intersects(piece.get_positions()) {
+                errors.push(format!("Piece {} overlaps with blocked cells or other pieces.", kind.as_str()));
+            }
+            occupied = &occupied + piece.get_positions();
+        }
+    }
+    let num_free = occupied.get_coweight();
+    if num_free > 0 {
+        errors.push(format!("{num_free} cell(s) are not covered."));
+    }
+
+    // check adjacency
+    for (k, kind1) in NON_ADJACENT.iter().enumerate() {
+        for kind2 in NON_ADJACENT[k + 1..].iter() {
+            if let (Some(piece1), Some(piece2)) = (pieces.get(kind1), pieces.get(kind2)) {
+                if piece1.get_positions().transform_dither().intersects(piece2.get_positions()) {
+                    errors.push(format!("Pieces {} and {} touch.", kind1.as_str(), kind2.as_str()));
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+    return Err(errors);
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+/// Determines whether the positions are a rotated/flipped/shifted copy of the shape of the piece.
+fn is_orientation_of(positions: &BinArray, kind: &EnumPiece) -> bool {
+    PieceInfo::of(kind).is_some_and(|info| info.find_orientation(positions).is_some())
+}
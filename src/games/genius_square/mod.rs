@@ -0,0 +1,63 @@
+//! Engine for the _Smart Games_ puzzle [Genius Square](https://smarttoysandgames.co.uk/uk/genius-square).
+//!
+//! The seven dice block seven cells of the 6 x 6 grid,
+//! and the nine pieces in [`ENUM_PIECES`] have to be placed
+//! to cover the remaining cells.
+//!
+//! # Example #
+//!
+//! ```no_run
+//! use general::_core::rand::seed_rng;
+//! use general::games::genius_square::*;
+//!
+//! let mut rng = seed_rng(Some("1234".to_string()));
+//! let faces = roll_dice(&mut rng);
+//! let board = GameBoard::new(&block_from_roll(&faces));
+//! println!("{}", board.pretty());
+//!
+//! let outcome = solve_propagating_with_options(&board, &SolveOptions::new());
+//! if let Some(solution) = outcome.board {
+//!     assert!(validate_solution(&solution).is_ok());
+//!     println!("{}", solution.pretty());
+//! }
+//! ```
+//!
+//! The submodules are
+//!
+//! - [`models`] for the arrays, pieces, dice and board of the game
+//!   as well as the geometry of the grid (square, hexagonal or triangular),
+//! - [`algorithms`] for solving and validating game states.
+
+pub mod algorithms;
+pub mod models;
+
+#[cfg(test)]
+pub mod tests_support;
+
+pub use algorithms::propagate::deduce;
+pub use algorithms::propagate::solve_propagating_with_options;
+pub use algorithms::propagate::solve_propagating_with_tree;
+pub use algorithms::solve::solve_brute_force;
+pub use algorithms::solve::solve_brute_force_with_options;
+pub use algorithms::solve::solve_brute_force_with_tree;
+pub use algorithms::solve::solve_pieces_with_options;
+pub use algorithms::solvers::SOLVERS;
+pub use algorithms::solvers::get_solver;
+pub use algorithms::validate::validate_solution;
+pub use models::arrays::models::BinArray;
+pub use models::board::models::GameBoard;
+pub use models::constants::enums::ENUM_PIECES;
+pub use models::constants::enums::EnumPiece;
+pub use models::dice::methods::block_from_roll;
+pub use models::dice::methods::roll_dice;
+pub use models::dice::models::Die;
+pub use models::geometry::models::GridGeometry;
+pub use models::geometry::models::HEX_GRID;
+pub use models::geometry::models::SQUARE_GRID;
+pub use models::geometry::models::TRI_GRID;
+pub use models::pieces::models::Piece;
+pub use models::solver::models::SearchNode;
+pub use models::solver::models::SolveOptions;
+pub use models::solver::models::SolveOutcome;
+pub use models::solver::models::SolveStatus;
+pub use models::solver::models::Solver;
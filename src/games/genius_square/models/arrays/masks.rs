@@ -0,0 +1,66 @@
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Set of cells of a board as a bitmask (row by row), in words of 64 cells.
+///
+/// Unlike [`BinArray`](super::models::BinArray),
+/// the operations in place do not allocate,
+/// which is what the solvers need in their inner loops.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CellMask {
+    /// number of columns of the board
+    n: usize,
+    words: Vec<u64>,
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl CellMask {
+    /// The empty set on a board of shape `m x n`
+    pub fn empty(m: usize, n: usize) -> Self {
+        Self {n, words: vec![0; (m * n).div_ceil(64)]}
+    }
+
+    /// The set of all cells on a board of shape `m x n`
+    pub fn full(m: usize, n: usize) -> Self {
+        let coords: Vec<(usize, usize)> = (0..m * n).map(|k| (k / n, k % n)).collect();
+        return Self::from_coords(&coords, m, n);
+    }
+
+    /// The given cells on a board of shape `m x n`
+    pub fn from_coords(coords: &[(usize, usize)], m: usize, n: usize) -> Self {
+        let mut mask = Self::empty(m, n);
+        for &(i, j) in coords {
+            let k = i * n + j;
+            mask.words[k / 64] |= 1 << (k % 64);
+        }
+        return mask;
+    }
+
+    pub fn contains(&self, cell: (usize, usize)) -> bool {
+        let (i, j) = cell;
+        let k = i * self.n + j;
+        return self.words[k / 64] & (1 << (k % 64)) != 0;
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.words.iter().zip(other.words.iter()).any(|(a, b)| a & b != 0)
+    }
+
+    /// Adds the cells of the other set.
+    pub fn insert(&mut self, other: &Self) {
+        for (a, b) in self.words.iter_mut().zip(other.words.iter()) {
+            *a |= b;
+        }
+    }
+
+    /// Removes the cells of the other set.
+    pub fn remove(&mut self, other: &Self) {
+        for (a, b) in self.words.iter_mut().zip(other.words.iter()) {
+            *a &= !b;
+        }
+    }
+}
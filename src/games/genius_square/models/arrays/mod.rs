@@ -0,0 +1,10 @@
+/// Models for handling arrays
+
+pub mod masks;
+pub mod models;
+pub mod morphology;
+
+#[cfg(test)]
+pub mod tests_models;
+#[cfg(test)]
+pub mod tests_morphology;
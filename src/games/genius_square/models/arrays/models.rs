@@ -0,0 +1,546 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use ndarray::Array2;
+use ndarray::Zip;
+use ndarray::s as slice;
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Add;
+use std::ops::BitXor;
+use std::ops::Mul;
+use std::ops::Not;
+use std::ops::Sub;
+use itertools::Itertools;
+
+use crate::games::genius_square::models::arrays::morphology::StructuringElement;
+use crate::games::genius_square::models::geometry::d4::D4;
+use crate::games::genius_square::models::geometry::models::Cell;
+use crate::games::genius_square::models::geometry::models::GridGeometry;
+use crate::games::genius_square::models::geometry::models::SQUARE_GRID;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Set of cells of an `m x n` array on a grid.
+///
+/// The operators act as set operations on arrays of the same shape
+/// (combining arrays of different shapes is a logic error and panics):
+///
+/// - `a + b` union,
+/// - `a * b` intersection,
+/// - `a - b` difference,
+/// - `a ^ b` symmetric difference,
+/// - `!a` complement (within the array).
+///
+/// Arrays are equal if they have the same shape, cells and geometry,
+/// and are ordered by shape, geometry and then row by row by their cells.
+#[derive(Clone, Debug)]
+pub struct BinArray {
+    m: usize,
+    n: usize,
+    values: Array2<u8>,
+    geometry: &'static dyn GridGeometry,
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl BinArray {
+    /// Creates an array on the square grid.
+    pub fn from_coords(
+        coords: Vec<(usize, usize)>,
+        m: usize,
+        n: usize,
+    ) -> Self {
+        Self::from_coords_on(&SQUARE_GRID, coords, m, n)
+    }
+
+    /// Creates an array on a grid of the given geometry.
+    pub fn from_coords_on(
+        geometry: &'static dyn GridGeometry,
+        coords: Vec<(usize, usize)>,
+        m: usize,
+        n: usize,
+    ) -> Self {
+        let mut values = Array2::<u8>::zeros((m, n));
+        for coord in coords {
+            values[[coord.0, coord.1]] = 1;
+        }
+        Self {m, n, values, geometry}
+    }
+
+    pub fn get_geometry(&self) -> &'static dyn GridGeometry {
+        self.geometry
+    }
+
+    /// Gets the list of co-ordinates of the entries which are non-zero
+    pub fn to_coords(&self) -> Vec<(usize, usize)> {
+        self.values
+            .indexed_iter()
+            .filter_map(|((i, j), &v)| if v == 0 { None } else { Some((i, j)) })
+            .collect()
+    }
+
+    /// Iterates over the co-ordinates of the entries which are non-zero (row by row)
+    pub fn iter_cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.values
+            .indexed_iter()
+            .filter_map(|((i, j), &v)| if v == 0 { None } else { Some((i, j)) })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.iter().all(|&v| v == 0)
+    }
+
+    /// Whether the cell is set (cells outside the array are never set)
+    pub fn contains(&self, cell: (usize, usize)) -> bool {
+        let (i, j) = cell;
+        self.values.get([i, j]).is_some_and(|&v| v != 0)
+    }
+
+    /// Whether every cell of the array is also a cell of the other array
+    /// (of the same shape).
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.debug_assert_same_shape(other);
+        Zip::from(&self.values).and(&other.values).all(|&a, &b| a == 0 || b != 0)
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Whether the arrays (of the same shape) have no cells in common
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.debug_assert_same_shape(other);
+        Zip::from(&self.values).and(&other.values).all(|&a, &b| a == 0 || b == 0)
+    }
+
+    /// Whether the arrays have cells in common (i.e. collide)
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.is_disjoint(other)
+    }
+
+    /// Determines a single co-ordinate to be used as an anchor point.
+    /// If none exists, defaults to (0, 0).
+    pub fn get_anchor(&self) -> (usize, usize) {
+        self.values
+            .indexed_iter()
+            .find_or_first(|&(_, &v)| v != 0)
+            .map_or((0, 0), |((i, j), _)| (i, j))
+    }
+
+    pub fn get_weight(&self) -> isize {
+        self.values
+            .mapv(|x| if x == 0 {0} else {1})
+            .sum()
+    }
+
+    pub fn get_coweight(&self) -> isize {
+        self.transform_invert().get_weight()
+    }
+
+    pub fn get_shape(&self) -> (usize, usize) {
+        (self.m, self.n)
+    }
+
+    pub fn get_values(&self) -> Array2<u8> {
+        self.values.clone()
+    }
+
+    /// Shifts array as far as possible to top left
+    /// (by translations which preserve the grid, see [`GridGeometry::normalise`])
+    pub fn recentre(&self) -> Self {
+        let coords: Vec<Cell> = self.to_coords().iter().map(|&(i, j)| (i as isize, j as isize)).collect();
+        let coords = self.geometry.normalise(&coords);
+        return self.with_cells(&coords);
+    }
+
+    /// Flips 0s and 1s
+    pub fn transform_invert(&self) -> Self {
+        let m = self.m;
+        let n = self.n;
+        let values = self.values.mapv(|x| if x == 0 {1} else {0});
+        return Self {m, n, values, geometry: self.geometry};
+    }
+
+    /// Shifts the array by the given number of rows and columns.
+    /// Cells which are shifted off the array are dropped
+    /// (cf. [`BinArray::checked_shift`]).
+    pub fn transform_shift(
+        &self,
+        di: isize,
+        dj: isize,
+    ) -> Self {
+        let coords: Vec<Cell> = self
+            .iter_cells()
+            .map(|(i, j)| (i as isize + di, j as isize + dj))
+            .collect();
+        return self.with_cells(&coords);
+    }
+
+    /// Shifts the array by the given number of rows and columns,
+    /// provided no cells are shifted off the array.
+    pub fn checked_shift(
+        &self,
+        di: isize,
+        dj: isize,
+    ) -> Option<Self> {
+        let coords = self
+            .iter_cells()
+            .map(|(i, j)| self.to_index((i as isize + di, j as isize + dj)))
+            .collect::<Option<Vec<(usize, usize)>>>()?;
+        return Some(Self::from_coords_on(self.geometry, coords, self.m, self.n));
+    }
+
+    /// NOTE: The flips, transposition and rotation by quarter turns
+    /// only preserve the square grid (see [`BinArray::transform_symmetry`] for other grids).
+    pub fn transform_hflip(&self, recentre: bool) -> Self{
+        let m = self.m;
+        let n = self.n;
+        let values = self.values.slice(slice![.., ..;-1]).to_owned();
+        let mut result = Self {m, n, values, geometry: self.geometry};
+        if recentre {
+            result = result.recentre();
+        }
+        return result;
+    }
+
+    pub fn transform_vflip(&self, recentre: bool) -> Self {
+        let m = self.m;
+        let n = self.n;
+        let values = self.values.slice(slice![..;-1, ..]).to_owned();
+        let mut result = Self {m, n, values, geometry: self.geometry};
+        if recentre {
+            result = result.recentre();
+        }
+        return result;
+
+    }
+
+    pub fn transform_transpose(&self, recentre: bool) -> Self {
+        let m = self.m;
+        let n = self.n;
+        let values = self.values.t().to_owned();
+        let mut result = Self {m, n, values, geometry: self.geometry};
+        if recentre {
+            result = result.recentre();
+        }
+        return result;
+    }
+
+    /// Rotates by `k` quarter turns anticlockwise (`k` may be negative).
+    pub fn transform_rotate(&self, k: i8, recentre: bool) -> Self {
+        match k.rem_euclid(4) {
+            1 => {
+                return self.transform_transpose(false).transform_vflip(recentre);
+            },
+            2 => {
+                return self.transform_vflip(false).transform_hflip(recentre);
+            },
+            3 => {
+                return self.transform_vflip(false).transform_transpose(recentre);
+            },
+            _ => {
+                if recentre {
+                    return self.recentre();
+                }
+                return self.clone();
+            }
+        }
+    }
+
+    /// Applies a symmetry of the square grid and shifts the result to the top left
+    /// (see [`BinArray::transform_symmetry`]).
+    pub fn transform_d4(&self, element: &D4) -> Self {
+        self.transform_symmetry(element.index())
+    }
+
+    /// Applies the `k`-th element of the point group of the geometry
+    /// (see [`GridGeometry::transform`]) and shifts the result to the top left.
+    pub fn transform_symmetry(&self, k: usize) -> Self {
+        let coords = self.transform_symmetry_cells(k);
+        return self.with_cells(&coords);
+    }
+
+    /// Determines all distinct orientations (shifted to the top left)
+    /// under the point group of the geometry.
+    pub fn get_orientations(&self) -> Vec<Self> {
+        self.get_distinct_symmetries()
+            .iter()
+            .map(|&k| self.transform_symmetry(k))
+            .collect()
+    }
+
+    /// Determines the first element of the point group (see [`GridGeometry::transform`])
+    /// leading to each distinct orientation, in the order of [`BinArray::get_orientations`].
+    pub fn get_distinct_symmetries(&self) -> Vec<usize> {
+        let mut used: Vec<Vec<Cell>> = vec![];
+        let mut result = vec![];
+        for k in 0..self.geometry.num_symmetries() {
+            let coords = self.transform_symmetry_cells(k);
+            if !used.contains(&coords) {
+                used.push(coords);
+                result.push(k);
+            }
+        }
+        return result;
+    }
+
+    /// For collision comparison:
+    /// adds all neighbours (see [`GridGeometry::neighbours`]) of the occupied cells.
+    pub fn transform_dither(&self) -> Self {
+        self.dilate(&StructuringElement::Neighbours)
+    }
+
+    /// Determines all possible configurations
+    /// of the same array subject to
+    ///
+    /// - the symmetries of the geometry (rotations and reflections),
+    /// - translations which preserve the grid,
+    ///
+    /// provided the moves keep the shape on the grid
+    /// and provided
+    ///
+    /// - no collisions occur with an optional obstacle.
+    pub fn get_configurations(
+        &self,
+        option_obst: Option<&BinArray>,
+    ) -> impl Iterator<Item = Self> {
+        let (m, n) = self.get_shape();
+        let obst = option_obst.map_or_else(|| BinArray::from_coords_on(self.geometry, vec![], m, n), |x| x.clone());
+        let free = obst.to_free_cells();
+        let arr = self.clone();
+        let iterator = self
+            .get_distinct_symmetries()
+            .into_iter()
+            // iterate through all distinct orientations
+            .map(move |k| arr.transform_symmetry_cells(k))
+            // by fixing an anchor point and viewing the non-occupied positions
+            // get all possible shifts of the array (flattening the possibilities)
+            .flat_map(move |coords| {
+                // an anchor point of the piece
+                let (i0, j0) = coords.first().copied().unwrap_or((0, 0));
+                // all non-occupied points on gameboard
+                let shifts = free
+                    .iter()
+                    .map(|&(i, j)| (i - i0, j - j0))
+                    .filter(|&(di, dj)| obst.geometry.is_translation(di, dj))
+                    .map(|(di, dj)| coords.iter().map(|&(i, j)| (i + di, j + dj)).collect::<Vec<Cell>>())
+                    .collect::<Vec<Vec<Cell>>>();
+                return shifts;
+            })
+            // if geometric operations shift shape off the grid
+            // or collide with obstacle, skip
+            .filter_map(move |coords| {
+                let mut cells: Vec<(usize, usize)> = vec![];
+                for &cell in coords.iter() {
+                    let (i, j) = cell_to_index(cell, m, n)?;
+                    if obst.values[[i, j]] != 0 {
+                        return None;
+                    }
+                    cells.push((i, j));
+                }
+                return Some(BinArray::from_coords_on(obst.geometry, cells, m, n));
+            });
+        return iterator;
+    }
+
+    /// Applies an element of the point group to the cells
+    /// and shifts them to the top left (sorted by rows then columns).
+    fn transform_symmetry_cells(&self, k: usize) -> Vec<Cell> {
+        let coords: Vec<Cell> = self
+            .to_coords()
+            .iter()
+            .map(|&(i, j)| self.geometry.transform((i as isize, j as isize), k))
+            .collect();
+        return self.geometry.normalise(&coords);
+    }
+
+    /// Combines the entries of two arrays of the same shape.
+    fn combine(&self, other: &Self, f: impl Fn(bool, bool) -> bool) -> Self {
+        self.debug_assert_same_shape(other);
+        let values = Zip::from(&self.values)
+            .and(&other.values)
+            .map_collect(|&a, &b| if f(a != 0, b != 0) { 1 } else { 0 });
+        return Self {m: self.m, n: self.n, values, geometry: self.geometry};
+    }
+
+    /// Checks the precondition of the set operations
+    /// (which otherwise fail inside the zip of the values with a less helpful message).
+    fn debug_assert_same_shape(&self, other: &Self) {
+        debug_assert_eq!(
+            self.get_shape(),
+            other.get_shape(),
+            "Set operations require arrays of the same shape.",
+        );
+    }
+
+    /// Array of the same kind with exactly the given cells occupied.
+    /// Cells outside the array are dropped.
+    fn with_cells(&self, coords: &[Cell]) -> Self {
+        let coords = coords.iter().filter_map(|&cell| self.to_index(cell)).collect();
+        return Self::from_coords_on(self.geometry, coords, self.m, self.n);
+    }
+
+    fn to_free_cells(&self) -> Vec<Cell> {
+        self.values
+            .indexed_iter()
+            .filter_map(|((i, j), &v)| if v == 0 { Some((i as isize, j as isize)) } else { None })
+            .collect()
+    }
+
+    fn to_index(&self, cell: Cell) -> Option<(usize, usize)> {
+        cell_to_index(cell, self.m, self.n)
+    }
+}
+
+impl Display for BinArray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.values)
+    }
+}
+
+impl PartialEq for BinArray {
+    fn eq(&self, other: &Self) -> bool {
+        self.m == other.m
+            && self.n == other.n
+            && self.geometry.name() == other.geometry.name()
+            && self.values == other.values
+    }
+}
+
+impl Eq for BinArray {}
+
+impl Hash for BinArray {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.m.hash(state);
+        self.n.hash(state);
+        self.geometry.name().hash(state);
+        self.values.hash(state);
+    }
+}
+
+impl PartialOrd for BinArray {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BinArray {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.m, self.n, self.geometry.name())
+            .cmp(&(other.m, other.n, other.geometry.name()))
+            .then_with(|| self.values.iter().cmp(other.values.iter()))
+    }
+}
+
+impl IntoIterator for &BinArray {
+    type Item = (usize, usize);
+    type IntoIter = std::vec::IntoIter<(usize, usize)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_coords().into_iter()
+    }
+}
+
+impl Add for BinArray {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        &self + &other
+    }
+}
+
+impl Add for &BinArray {
+    type Output = BinArray;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.combine(other, |a, b| a || b)
+    }
+}
+
+impl Mul for BinArray {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        &self * &other
+    }
+}
+
+impl Mul for &BinArray {
+    type Output = BinArray;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.combine(other, |a, b| a && b)
+    }
+}
+
+impl Sub for BinArray {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        &self - &other
+    }
+}
+
+impl Sub for &BinArray {
+    type Output = BinArray;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.combine(other, |a, b| a && !b)
+    }
+}
+
+impl BitXor for BinArray {
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self::Output {
+        &self ^ &other
+    }
+}
+
+impl BitXor for &BinArray {
+    type Output = BinArray;
+
+    fn bitxor(self, other: Self) -> Self::Output {
+        self.combine(other, |a, b| a != b)
+    }
+}
+
+impl Not for BinArray {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.transform_invert()
+    }
+}
+
+impl Not for &BinArray {
+    type Output = BinArray;
+
+    fn not(self) -> Self::Output {
+        self.transform_invert()
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+/// Position of a cell in an array of shape `m x n`, if it lies inside.
+pub fn cell_to_index(cell: Cell, m: usize, n: usize) -> Option<(usize, usize)> {
+    let (i, j) = cell;
+    if i < 0 || j < 0 || i as usize >= m || j as usize >= n {
+        return None;
+    }
+    return Some((i as usize, j as usize));
+}
@@ -0,0 +1,198 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use ndarray::Array2;
+use std::collections::VecDeque;
+
+use crate::games::genius_square::models::arrays::models::BinArray;
+use crate::games::genius_square::models::arrays::models::cell_to_index;
+use crate::games::genius_square::models::geometry::models::Cell;
+use crate::games::genius_square::models::geometry::models::GridGeometry;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Neighbourhood of a cell used by the morphological operations on [`BinArray`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StructuringElement {
+    /// the cell and its neighbours in the geometry of the array
+    /// (see [`GridGeometry::neighbours`])
+    Neighbours,
+    /// the cell and the 4 cells sharing an edge with it (square grid)
+    Cross,
+    /// the cell and the 8 cells surrounding it (square grid)
+    Square,
+    /// the cells at the offsets `(di, dj)` from the cell
+    Custom(Vec<Cell>),
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl StructuringElement {
+    /// The cells covered by the element centred at the cell
+    /// (possibly outside the array).
+    pub fn cover(&self, geometry: &dyn GridGeometry, cell: Cell) -> Vec<Cell> {
+        let (i, j) = cell;
+        match self {
+            Self::Neighbours => {
+                let mut cells = vec![cell];
+                cells.extend(geometry.neighbours(cell));
+                cells
+            },
+            Self::Cross => vec![(i, j), (i - 1, j), (i + 1, j), (i, j - 1), (i, j + 1)],
+            Self::Square => (-1..=1)
+                .flat_map(|di| (-1..=1).map(move |dj| (i + di, j + dj)))
+                .collect(),
+            Self::Custom(offsets) => offsets.iter().map(|&(di, dj)| (i + di, j + dj)).collect(),
+        }
+    }
+
+    /// Cells joined to the cell by the element in either direction,
+    /// used for connectivity (cf. [`BinArray::components`]).
+    fn links(&self, geometry: &dyn GridGeometry, cell: Cell) -> Vec<Cell> {
+        let (i, j) = cell;
+        let mut cells = self.cover(geometry, cell);
+        if let Self::Custom(offsets) = self {
+            cells.extend(offsets.iter().map(|&(di, dj)| (i - di, j - dj)));
+        }
+        return cells;
+    }
+}
+
+/// Binary morphology.
+///
+/// The element `B` is placed at every cell `c` of the array:
+///
+/// - the dilation consists of the cells `c + b` for `c` in the array,
+/// - the erosion consists of the cells `c` with `c + b` in the array for all `b`.
+///
+/// Cells outside the array are ignored, i.e. they are dropped by the dilation
+/// and place no condition on the erosion.
+/// In particular the erosion of the full array is the full array,
+/// and the opening and closing satisfy `open(A) ⊆ A ⊆ close(A)`
+/// if the element contains its centre.
+impl BinArray {
+    pub fn dilate(&self, element: &StructuringElement) -> Self {
+        let (m, n) = self.get_shape();
+        let coords = self
+            .iter_cells()
+            .flat_map(|(i, j)| element.cover(self.get_geometry(), (i as isize, j as isize)))
+            .filter_map(|cell| cell_to_index(cell, m, n))
+            .collect();
+        return Self::from_coords_on(self.get_geometry(), coords, m, n);
+    }
+
+    pub fn erode(&self, element: &StructuringElement) -> Self {
+        let (m, n) = self.get_shape();
+        let coords = self
+            .iter_cells()
+            .filter(|&(i, j)| {
+                element
+                    .cover(self.get_geometry(), (i as isize, j as isize))
+                    .into_iter()
+                    .filter_map(|cell| cell_to_index(cell, m, n))
+                    .all(|cell| self.contains(cell))
+            })
+            .collect();
+        return Self::from_coords_on(self.get_geometry(), coords, m, n);
+    }
+
+    /// Erosion followed by dilation:
+    /// removes the parts of the array which the element does not fit into.
+    pub fn open(&self, element: &StructuringElement) -> Self {
+        self.erode(element).dilate(element)
+    }
+
+    /// Dilation followed by erosion:
+    /// fills the gaps of the array which the element does not fit into.
+    pub fn close(&self, element: &StructuringElement) -> Self {
+        self.dilate(element).erode(element)
+    }
+
+    /// Cells of the array which are not in its erosion (i.e. its outline).
+    pub fn boundary(&self, element: &StructuringElement) -> Self {
+        self - &self.erode(element)
+    }
+
+    /// Cells of the dilation which are not in the array (i.e. the cells touching it).
+    pub fn outer_boundary(&self, element: &StructuringElement) -> Self {
+        &self.dilate(element) - self
+    }
+
+    /// Number of dilations needed to reach each cell from the array,
+    /// or `None` if the cell cannot be reached (e.g. if the array is empty).
+    pub fn distance_transform(&self, element: &StructuringElement) -> Array2<Option<usize>> {
+        let (m, n) = self.get_shape();
+        let mut distances = Array2::<Option<usize>>::from_elem((m, n), None);
+        let mut queue = VecDeque::new();
+        for (i, j) in self.iter_cells() {
+            distances[[i, j]] = Some(0);
+            queue.push_back((i, j));
+        }
+        // breadth first search, i.e. by repeated dilation
+        while let Some((i, j)) = queue.pop_front() {
+            let distance = distances[[i, j]].unwrap();
+            for cell in element.cover(self.get_geometry(), (i as isize, j as isize)) {
+                let Some((i, j)) = cell_to_index(cell, m, n) else {
+                    continue;
+                };
+                if distances[[i, j]].is_none() {
+                    distances[[i, j]] = Some(distance + 1);
+                    queue.push_back((i, j));
+                }
+            }
+        }
+        return distances;
+    }
+
+    /// Connected components of the array,
+    /// where cells are connected if the element joins them.
+    /// The components are ordered by their first cell (row by row).
+    pub fn components(&self, element: &StructuringElement) -> Vec<Self> {
+        let (m, n) = self.get_shape();
+        let mut seen = Array2::<bool>::from_elem((m, n), false);
+        let mut components = vec![];
+        for start in self.iter_cells() {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut coords = vec![];
+            let mut stack = vec![start];
+            while let Some((i, j)) = stack.pop() {
+                coords.push((i, j));
+                for cell in element.links(self.get_geometry(), (i as isize, j as isize)) {
+                    let Some(index) = cell_to_index(cell, m, n) else {
+                        continue;
+                    };
+                    if self.contains(index) && !seen[index] {
+                        seen[index] = true;
+                        stack.push(index);
+                    }
+                }
+            }
+            components.push(Self::from_coords_on(self.get_geometry(), coords, m, n));
+        }
+        return components;
+    }
+
+    /// Cells outside the array which are enclosed by it,
+    /// i.e. the components of the complement which do not touch the edge of the array.
+    pub fn holes(&self, element: &StructuringElement) -> Self {
+        let (m, n) = self.get_shape();
+        let mut result = Self::from_coords_on(self.get_geometry(), vec![], m, n);
+        for component in (!self).components(element) {
+            let touches_edge = component
+                .iter_cells()
+                .any(|(i, j)| i == 0 || j == 0 || i + 1 == m || j + 1 == n);
+            if !touches_edge {
+                result = &result + &component;
+            }
+        }
+        return result;
+    }
+}
@@ -0,0 +1,132 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+use super::models::*;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_operations() {
+        let a = create_array(&[(0, 0), (0, 1), (1, 1)]);
+        let b = create_array(&[(1, 1), (2, 2)]);
+        assert_eq!(&a + &b, create_array(&[(0, 0), (0, 1), (1, 1), (2, 2)]));
+        assert_eq!(&a * &b, create_array(&[(1, 1)]));
+        assert_eq!(&a - &b, create_array(&[(0, 0), (0, 1)]));
+        assert_eq!(&a ^ &b, create_array(&[(0, 0), (0, 1), (2, 2)]));
+        assert_eq!((!&a).get_weight(), 33);
+        assert!(a.intersects(&b));
+        assert!((&a - &b).is_disjoint(&b));
+        assert!((&a * &b).is_subset(&a));
+        assert!(a.contains((0, 1)));
+        assert!(!a.contains((6, 6)));
+        assert_eq!(a.iter_cells().collect::<Vec<_>>(), vec![(0, 0), (0, 1), (1, 1)]);
+        assert_eq!((&a).into_iter().count(), 3);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "same shape")]
+    fn test_set_operations_of_different_shapes() {
+        let a = create_array(&[(0, 0)]);
+        let b = BinArray::from_coords(vec![(0, 0)], 3, 3);
+        let _ = a.is_subset(&b);
+    }
+
+    #[test]
+    fn test_shift_off_grid() {
+        let a = create_array(&[(0, 0), (5, 5)]);
+        assert_eq!(a.checked_shift(1, 0), None);
+        assert_eq!(a.transform_shift(1, 0), create_array(&[(1, 0)]));
+        // shifts beyond the size of the grid used to panic
+        assert!(a.transform_shift(20, -20).is_empty());
+        assert_eq!(a.checked_shift(-7, 0), None);
+        assert_eq!(a.checked_shift(0, 0), Some(a.clone()));
+    }
+
+    #[test]
+    fn test_equality_and_hashing() {
+        let a = create_array(&[(0, 0), (1, 2)]);
+        let b = create_array(&[(1, 2)]) + create_array(&[(0, 0)]);
+        assert_eq!(a, b);
+        let set: HashSet<BinArray> = [a.clone(), b.clone(), create_array(&[])].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert!(create_array(&[]) < a);
+        assert_ne!(a, BinArray::from_coords(vec![(0, 0), (1, 2)], 6, 7));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_boolean_algebra(a in arb_array(), b in arb_array(), c in arb_array()) {
+            // commutativity and associativity
+            prop_assert_eq!(&a + &b, &b + &a);
+            prop_assert_eq!(&a * &b, &b * &a);
+            prop_assert_eq!(&a ^ &b, &b ^ &a);
+            prop_assert_eq!(&(&a + &b) + &c, &a + &(&b + &c));
+            prop_assert_eq!(&(&a * &b) * &c, &a * &(&b * &c));
+            prop_assert_eq!(&(&a ^ &b) ^ &c, &a ^ &(&b ^ &c));
+            // distributivity
+            prop_assert_eq!(&a * &(&b + &c), &(&a * &b) + &(&a * &c));
+            prop_assert_eq!(&a + &(&b * &c), &(&a + &b) * &(&a + &c));
+            // complement and De Morgan
+            prop_assert_eq!(!!a.clone(), a.clone());
+            prop_assert_eq!(!(&a + &b), &!&a * &!&b);
+            prop_assert_eq!(!(&a * &b), &!&a + &!&b);
+            prop_assert!((&a * &!&a).is_empty());
+            // difference and symmetric difference
+            prop_assert_eq!(&a - &b, &a * &!&b);
+            prop_assert_eq!(&a ^ &b, &(&a - &b) + &(&b - &a));
+            prop_assert!((&a ^ &a).is_empty());
+        }
+
+        #[test]
+        fn prop_order_relations(a in arb_array(), b in arb_array()) {
+            prop_assert_eq!(a.is_subset(&b), &a + &b == b);
+            prop_assert_eq!(a.is_subset(&b), &a * &b == a);
+            prop_assert_eq!(a.is_disjoint(&b), (&a * &b).is_empty());
+            prop_assert_eq!(a.intersects(&b), (&a * &b).get_weight() > 0);
+            prop_assert!((&a * &b).is_subset(&a) && (&a + &b).is_superset(&a));
+            prop_assert_eq!(a.cmp(&b) == std::cmp::Ordering::Equal, a == b);
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            prop_assert_eq!(a.iter_cells().count() as isize, a.get_weight());
+        }
+
+        #[test]
+        fn prop_shifts(a in arb_array(), di in -8isize..8, dj in -8isize..8) {
+            let shifted = a.transform_shift(di, dj);
+            prop_assert!(shifted.get_weight() <= a.get_weight());
+            match a.checked_shift(di, dj) {
+                Some(b) => {
+                    prop_assert_eq!(&b, &shifted);
+                    prop_assert_eq!(b.get_weight(), a.get_weight());
+                    prop_assert_eq!(b.checked_shift(-di, -dj), Some(a.clone()));
+                },
+                None => {
+                    prop_assert!(shifted.get_weight() < a.get_weight());
+                },
+            }
+        }
+    }
+
+    /// Random arrays on the 6 x 6 grid
+    fn arb_array() -> impl Strategy<Value = BinArray> {
+        any::<u64>().prop_map(|bits| {
+            let coords = (0..36).filter(|k| bits & (1 << k) != 0).map(|k| (k / 6, k % 6)).collect();
+            BinArray::from_coords(coords, 6, 6)
+        })
+    }
+
+    fn create_array(coords: &[(usize, usize)]) -> BinArray {
+        BinArray::from_coords(coords.to_vec(), 6, 6)
+    }
+}
@@ -0,0 +1,134 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use proptest::prelude::*;
+use rstest::*;
+
+use super::models::*;
+use super::morphology::*;
+use crate::games::genius_square::models::geometry::models::HEX_GRID;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest]
+    #[case(StructuringElement::Neighbours, 5)]
+    #[case(StructuringElement::Cross, 5)]
+    #[case(StructuringElement::Square, 9)]
+    #[case(StructuringElement::Custom(vec![(0, 0), (0, 1), (0, 2)]), 3)]
+    fn test_dilate_single_cell(#[case] element: StructuringElement, #[case] weight: isize) {
+        let a = create_array(&[(2, 2)]);
+        assert_eq!(a.dilate(&element).get_weight(), weight);
+        assert_eq!(a.dilate(&element).erode(&element), a);
+    }
+
+    #[test]
+    fn test_dither_is_dilation() {
+        let a = create_array(&[(0, 0), (3, 4)]);
+        let expected = create_array(&[(0, 0), (0, 1), (1, 0), (2, 4), (3, 3), (3, 4), (3, 5), (4, 4)]);
+        assert_eq!(a.transform_dither(), expected);
+        // on the hexagonal grid every cell has 6 neighbours
+        let b = BinArray::from_coords_on(&HEX_GRID, vec![(2, 2)], 5, 5);
+        assert_eq!(b.transform_dither().get_weight(), 7);
+    }
+
+    #[test]
+    fn test_boundary_and_distance() {
+        // a 3 x 3 block in the top left corner
+        let a = create_array(&[(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2), (2, 0), (2, 1), (2, 2)]);
+        let element = StructuringElement::Cross;
+        // the edge of the array does not count as outside
+        assert_eq!(a.erode(&element), create_array(&[(0, 0), (0, 1), (1, 0), (1, 1)]));
+        assert_eq!(a.boundary(&element).get_weight(), 5);
+        assert_eq!(a.outer_boundary(&element).get_weight(), 6);
+        let distances = a.distance_transform(&element);
+        assert_eq!(distances[[1, 1]], Some(0));
+        assert_eq!(distances[[3, 3]], Some(2));
+        assert_eq!(distances[[5, 5]], Some(6));
+        assert_eq!(distances[[5, 5]], a.distance_transform(&StructuringElement::Square)[[5, 5]].map(|d| d * 2));
+        assert!(create_array(&[]).distance_transform(&element).iter().all(|d| d.is_none()));
+    }
+
+    #[test]
+    fn test_components_and_holes() {
+        // a ring around (2, 2) and a diagonal pair
+        let ring = create_array(&[(1, 1), (1, 2), (1, 3), (2, 1), (2, 3), (3, 1), (3, 2), (3, 3)]);
+        let pair = create_array(&[(5, 4), (4, 5)]);
+        let a = &ring + &pair;
+        assert_eq!(a.components(&StructuringElement::Cross).len(), 3);
+        assert_eq!(a.components(&StructuringElement::Square).len(), 2);
+        assert_eq!(a.holes(&StructuringElement::Cross), create_array(&[(2, 2)]));
+        assert_eq!(a.holes(&StructuringElement::Square), create_array(&[(2, 2)]));
+        assert!(pair.holes(&StructuringElement::Cross).is_empty());
+        // a diamond only encloses its centre if diagonal steps are excluded
+        let diamond = create_array(&[(1, 2), (2, 1), (2, 3), (3, 2)]);
+        assert_eq!(diamond.holes(&StructuringElement::Cross), create_array(&[(2, 2)]));
+        assert!(diamond.holes(&StructuringElement::Square).is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_morphology(a in arb_array(), b in arb_array(), k in 0usize..4) {
+            let element = [
+                StructuringElement::Neighbours,
+                StructuringElement::Cross,
+                StructuringElement::Square,
+                StructuringElement::Custom(vec![(0, 0), (1, 0), (1, 1)]),
+            ][k].clone();
+            // extensivity and monotonicity
+            prop_assert!(a.erode(&element).is_subset(&a));
+            prop_assert!(a.is_subset(&a.dilate(&element)));
+            prop_assert!((&a * &b).dilate(&element).is_subset(&a.dilate(&element)));
+            prop_assert_eq!((&a + &b).dilate(&element), &a.dilate(&element) + &b.dilate(&element));
+            prop_assert_eq!((&a * &b).erode(&element), &a.erode(&element) * &b.erode(&element));
+            // opening and closing
+            let opened = a.open(&element);
+            let closed = a.close(&element);
+            prop_assert!(opened.is_subset(&a) && a.is_subset(&closed));
+            prop_assert_eq!(opened.open(&element), opened.clone());
+            prop_assert_eq!(closed.close(&element), closed.clone());
+            // boundaries and components partition the cells
+            prop_assert_eq!(&a.boundary(&element) + &a.erode(&element), a.clone());
+            prop_assert!(a.outer_boundary(&element).is_disjoint(&a));
+            let components = a.components(&element);
+            prop_assert_eq!(components.iter().map(|c| c.get_weight()).sum::<isize>(), a.get_weight());
+            prop_assert!(a.holes(&element).is_disjoint(&a));
+        }
+
+        #[test]
+        fn prop_symmetric_duality(a in arb_array()) {
+            // for symmetric elements erosion is dual to dilation
+            for element in [StructuringElement::Cross, StructuringElement::Square] {
+                prop_assert_eq!(a.erode(&element), !(!&a).dilate(&element));
+                let distances = a.distance_transform(&element);
+                prop_assert_eq!(
+                    a.dilate(&element),
+                    BinArray::from_coords(
+                        distances.indexed_iter().filter(|(_, d)| d.is_some_and(|d| d <= 1)).map(|(cell, _)| cell).collect(),
+                        6,
+                        6,
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Random arrays on the 6 x 6 grid
+    fn arb_array() -> impl Strategy<Value = BinArray> {
+        any::<u64>().prop_map(|bits| {
+            let coords = (0..36).filter(|k| bits & (1 << k) != 0).map(|k| (k / 6, k % 6)).collect();
+            BinArray::from_coords(coords, 6, 6)
+        })
+    }
+
+    fn create_array(coords: &[(usize, usize)]) -> BinArray {
+        BinArray::from_coords(coords.to_vec(), 6, 6)
+    }
+}
@@ -0,0 +1,6 @@
+/// Models for benchmarking the solver
+
+pub mod models;
+
+#[cfg(test)]
+pub mod tests_models;
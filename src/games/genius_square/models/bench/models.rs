@@ -0,0 +1,163 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result;
+use std::time::Duration;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Measurement of a single solver run within a benchmark.
+#[derive(Clone, Copy, Debug)]
+pub struct BenchSample {
+    pub elapsed: Duration,
+    pub nodes: u64,
+    pub solved: bool,
+    pub allocations: Option<u64>,
+}
+
+/// Aggregated measurements of one strategy over the entire corpus.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BenchRecord {
+    pub strategy: String,
+    pub num_rolls: usize,
+    pub num_solved: usize,
+    pub median_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+    pub nodes_per_sec: f64,
+    pub allocations_per_roll: Option<f64>,
+}
+
+/// Results of a benchmark over a corpus of rolls.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub seed: String,
+    pub num_rolls: usize,
+    pub records: Vec<BenchRecord>,
+}
+
+/// A strategy which got slower between two benchmark reports.
+#[derive(Clone, Debug)]
+pub struct BenchRegression {
+    pub strategy: String,
+    pub before_ms: f64,
+    pub after_ms: f64,
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl BenchRecord {
+    pub fn from_samples(strategy: &str, samples: &[BenchSample]) -> Self {
+        let mut times: Vec<f64> = samples
+            .iter()
+            .map(|sample| sample.elapsed.as_secs_f64() * 1000.)
+            .collect();
+        times.sort_by(|a, b| a.total_cmp(b));
+        let total_secs: f64 = samples.iter().map(|sample| sample.elapsed.as_secs_f64()).sum();
+        let total_nodes: u64 = samples.iter().map(|sample| sample.nodes).sum();
+        let nodes_per_sec = if total_secs > 0. { total_nodes as f64 / total_secs } else { 0. };
+        let allocations: Option<Vec<u64>> = samples.iter().map(|sample| sample.allocations).collect();
+        let allocations_per_roll = allocations
+            .filter(|values| !values.is_empty())
+            .map(|values| values.iter().sum::<u64>() as f64 / values.len() as f64);
+        return Self {
+            strategy: strategy.to_string(),
+            num_rolls: samples.len(),
+            num_solved: samples.iter().filter(|sample| sample.solved).count(),
+            median_ms: percentile(&times, 50.),
+            p90_ms: percentile(&times, 90.),
+            p99_ms: percentile(&times, 99.),
+            max_ms: times.last().copied().unwrap_or(0.),
+            nodes_per_sec,
+            allocations_per_roll,
+        };
+    }
+}
+
+impl BenchReport {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn from_json(text: &str) -> std::result::Result<Self, String> {
+        serde_json::from_str(text).map_err(|err| err.to_string())
+    }
+
+    /// Compares the median times against a baseline report
+    /// and returns all strategies which slowed down by more than
+    /// the given threshold (as a fraction, e.g. `0.1` for 10%).
+    pub fn compare(&self, baseline: &BenchReport, threshold: f64) -> Vec<BenchRegression> {
+        self.records
+            .iter()
+            .filter_map(|record| {
+                let before = baseline.records.iter().find(|r| r.strategy == record.strategy)?;
+                if before.median_ms <= 0. {
+                    return None;
+                }
+                if record.median_ms > before.median_ms * (1. + threshold) {
+                    return Some(BenchRegression {
+                        strategy: record.strategy.clone(),
+                        before_ms: before.median_ms,
+                        after_ms: record.median_ms,
+                    });
+                }
+                return None;
+            })
+            .collect()
+    }
+}
+
+impl Display for BenchRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let allocs = self.allocations_per_roll.map_or("n/a".to_string(), |x| format!("{x:.0}"));
+        write!(
+            f,
+            "{}: solved {}/{}, median {:.2}ms, p90 {:.2}ms, p99 {:.2}ms, max {:.2}ms, {:.0} nodes/s, {} allocations/roll",
+            self.strategy,
+            self.num_solved,
+            self.num_rolls,
+            self.median_ms,
+            self.p90_ms,
+            self.p99_ms,
+            self.max_ms,
+            self.nodes_per_sec,
+            allocs,
+        )
+    }
+}
+
+impl Display for BenchRegression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let change = (self.after_ms / self.before_ms - 1.) * 100.;
+        write!(
+            f,
+            "{}: median {:.2}ms -> {:.2}ms (+{:.1}%)",
+            self.strategy, self.before_ms, self.after_ms, change,
+        )
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+/// Nearest-rank percentile of sorted values
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.;
+    }
+    let rank = ((p / 100.) * sorted.len() as f64).ceil() as usize;
+    let index = rank.clamp(1, sorted.len()) - 1;
+    return sorted[index];
+}
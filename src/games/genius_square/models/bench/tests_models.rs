@@ -0,0 +1,118 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use rstest::rstest;
+use std::time::Duration;
+
+use super::models::*;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_samples() {
+        let record = BenchRecord::from_samples("brute-force", &[]);
+        assert_eq!(record.num_rolls, 0);
+        assert_eq!((record.median_ms, record.p90_ms, record.p99_ms, record.max_ms), (0., 0., 0., 0.));
+        assert_eq!(record.nodes_per_sec, 0.);
+        assert_eq!(record.allocations_per_roll, None);
+    }
+
+    #[test]
+    fn test_single_sample() {
+        let record = BenchRecord::from_samples("brute-force", &create_samples(&[7]));
+        assert_eq!((record.median_ms, record.p90_ms, record.p99_ms, record.max_ms), (7., 7., 7., 7.));
+        assert!((record.nodes_per_sec - 1000.).abs() < 1e-6);
+        assert_eq!(record.allocations_per_roll, Some(10.));
+    }
+
+    #[rstest]
+    #[case(&[3, 1, 2], (2., 3., 3.))]
+    #[case(&[4, 1, 3, 2], (2., 4., 4.))]
+    #[case(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10], (5., 9., 10.))]
+    fn test_percentiles(#[case] times_ms: &[u64], #[case] expected: (f64, f64, f64)) {
+        // nearest rank, i.e. always one of the samples
+        let record = BenchRecord::from_samples("brute-force", &create_samples(times_ms));
+        assert_eq!((record.median_ms, record.p90_ms, record.p99_ms), expected);
+        assert_eq!(record.max_ms, *times_ms.iter().max().unwrap() as f64);
+    }
+
+    #[test]
+    fn test_unknown_allocations() {
+        let mut samples = create_samples(&[1, 2]);
+        samples[1].allocations = None;
+        let record = BenchRecord::from_samples("brute-force", &samples);
+        assert_eq!(record.allocations_per_roll, None);
+    }
+
+    #[rstest]
+    #[case(10., 10., 0.1, false)]
+    #[case(10., 11., 0.1, false)]
+    #[case(10., 11.5, 0.1, true)]
+    #[case(10., 8., 0.1, false)]
+    #[case(10., 10.5, 0., true)]
+    #[case(0., 5., 0.1, false)]
+    fn test_compare(#[case] before_ms: f64, #[case] after_ms: f64, #[case] threshold: f64, #[case] regressed: bool) {
+        let baseline = create_report(&[("brute-force", before_ms)]);
+        let report = create_report(&[("brute-force", after_ms)]);
+        let regressions = report.compare(&baseline, threshold);
+        assert_eq!(regressions.len(), if regressed { 1 } else { 0 });
+        if let Some(regression) = regressions.first() {
+            assert_eq!(regression.strategy, "brute-force");
+            assert_eq!((regression.before_ms, regression.after_ms), (before_ms, after_ms));
+        }
+    }
+
+    #[test]
+    fn test_compare_new_strategy() {
+        // strategies missing from the baseline are not regressions
+        let baseline = create_report(&[("brute-force", 10.)]);
+        let report = create_report(&[("brute-force", 10.), ("propagate", 100.)]);
+        assert!(report.compare(&baseline, 0.1).is_empty());
+    }
+
+    #[test]
+    fn test_json() {
+        let report = create_report(&[("brute-force", 1.5), ("propagate", 0.5)]);
+        let report_ = BenchReport::from_json(&report.to_json()).unwrap();
+        assert_eq!(report_.to_json(), report.to_json());
+        assert!(BenchReport::from_json("{}").is_err());
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Solved samples with the given times (in ms), 1 node per ms and 10 allocations each
+fn create_samples(times_ms: &[u64]) -> Vec<BenchSample> {
+    times_ms
+        .iter()
+        .map(|&t| BenchSample {
+            elapsed: Duration::from_millis(t),
+            nodes: t,
+            solved: true,
+            allocations: Some(10),
+        })
+        .collect()
+}
+
+/// Report with the given median times (in ms) per strategy
+fn create_report(medians: &[(&str, f64)]) -> BenchReport {
+    let records = medians
+        .iter()
+        .map(|&(strategy, median_ms)| {
+            let mut record = BenchRecord::from_samples(strategy, &create_samples(&[1]));
+            record.median_ms = median_ms;
+            record
+        })
+        .collect();
+    return BenchReport {seed: "1234".to_string(), num_rolls: 1, records};
+}
@@ -0,0 +1,194 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use crate::games::genius_square::models::constants::enums::*;
+use crate::games::genius_square::models::constants::pieces::SYMB_BLOCK;
+use crate::games::genius_square::models::dice::models::Die;
+use crate::games::genius_square::models::pieces::models::Piece;
+use crate::games::genius_square::models::record::models::Placement;
+use super::models::GameBoard;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Creates the blocking piece from a list of cell labels (e.g. `B2`).
+pub fn block_from_cells(
+    cells: &[String],
+) -> Result<Piece, String> {
+    let coords = cells
+        .iter()
+        .map(String::as_str)
+        .map(parse_cell)
+        .collect::<Result<Vec<(usize, usize)>, String>>()?;
+    return Ok(Piece::from_coords(coords, Some(EnumPiece::Block)));
+}
+
+/// Parses a solution either as placements (see [`board_from_placements`])
+/// if it contains a `=`, in the notation (see [`board_from_notation`])
+/// if it contains a `:`, or else as a grid (see [`board_from_grid`]).
+pub fn board_from_text(
+    text: &str,
+    block: &Piece,
+) -> Result<GameBoard, String> {
+    if text.contains('=') {
+        return board_from_placements(text, block);
+    }
+    if text.contains(':') {
+        return board_from_notation(text, block);
+    }
+    return board_from_grid(text, block);
+}
+
+/// Parses a solution entered as a grid,
+/// i.e. one row per line and one piece symbol per cell, e.g.
+///
+/// ```text
+/// 1■22Z■
+/// LXXZZ■
+/// ...
+/// ```
+///
+/// Whitespace and the separators `│`, `|` are ignored.
+/// Blocked cells may be marked by `■` or `#`.
+pub fn board_from_grid(
+    text: &str,
+    block: &Piece,
+) -> Result<GameBoard, String> {
+    let rows: Vec<Vec<String>> = text
+        .lines()
+        .map(|line| {
+            line.chars()
+                .filter(|c| !c.is_whitespace() && *c != '\u{2502}' && *c != '|')
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+        })
+        .filter(|row| !row.is_empty())
+        .collect();
+    let (m, n) = block.get_positions().get_shape();
+    if rows.len() != m {
+        return Err(format!("Expected {m} rows but got {}.", rows.len()));
+    }
+
+    let mut cells: HashMap<EnumPiece, Vec<(usize, usize)>> = HashMap::new();
+    for (i, row) in rows.iter().enumerate() {
+        if row.len() != n {
+            return Err(format!("Expected {n} cells in row {} but got {}.", i + 1, row.len()));
+        }
+        for (j, symb) in row.iter().enumerate() {
+            if symb == SYMB_BLOCK || symb == "#" {
+                continue;
+            }
+            let kind = EnumPiece::from_symb(symb)
+                .ok_or_else(|| format!("Unknown piece {symb} in row {}.", i + 1))?;
+            cells.entry(kind).or_default().push((i, j));
+        }
+    }
+
+    let geometry = block.get_positions().get_geometry();
+    let mut board = GameBoard::new(block);
+    for (kind, coords) in cells {
+        let piece = Piece::from_coords_on(geometry, coords, m, n, Some(kind.clone()));
+        board.add_piece(&kind, &piece);
+    }
+    return Ok(board);
+}
+
+/// Parses a solution entered as a list of placements,
+/// each consisting of a piece symbol and the cells it covers, e.g.
+///
+/// ```text
+/// 1=A1; 2=C1,D1; L=A2,A3,A4,B4; ...
+/// ```
+///
+/// Placements are separated by `;` or whitespace.
+pub fn board_from_placements(
+    text: &str,
+    block: &Piece,
+) -> Result<GameBoard, String> {
+    let (m, n) = block.get_positions().get_shape();
+    let geometry = block.get_positions().get_geometry();
+    let mut board = GameBoard::new(block);
+    for placement in text.split(|c: char| c == ';' || c.is_whitespace()).filter(|x| !x.is_empty()) {
+        let (symb, cells) = placement
+            .split_once('=')
+            .ok_or_else(|| format!("Placement {placement} must be of the form PIECE=CELL,CELL,..."))?;
+        let kind = EnumPiece::from_symb(symb)
+            .ok_or_else(|| format!("Unknown piece {symb}."))?;
+        if board.get_pieces().contains_key(&kind) {
+            return Err(format!("Piece {symb} placed more than once."));
+        }
+        let coords = cells
+            .split(',')
+            .map(|cell| parse_cell_on(cell, block))
+            .collect::<Result<Vec<(usize, usize)>, String>>()?;
+        let piece = Piece::from_coords_on(geometry, coords, m, n, Some(kind.clone()));
+        board.add_piece(&kind, &piece);
+    }
+    return Ok(board);
+}
+
+/// Parses a solution entered in the notation of placements (see [`Placement`]), e.g.
+///
+/// ```text
+/// 1:A1; 2:C1:r1; L:A2:r0:h; ...
+/// ```
+///
+/// Placements are separated by `;` or whitespace.
+pub fn board_from_notation(
+    text: &str,
+    block: &Piece,
+) -> Result<GameBoard, String> {
+    let (m, n) = block.get_positions().get_shape();
+    let mut board = GameBoard::new(block);
+    for entry in text.split(|c: char| c == ';' || c.is_whitespace()).filter(|x| !x.is_empty()) {
+        let placement: Placement = entry.parse()?;
+        if board.get_pieces().contains_key(&placement.kind) {
+            return Err(format!("Piece {} placed more than once.", placement.kind.as_str()));
+        }
+        board.add_piece(&placement.kind, &placement.to_piece_on(m, n)?);
+    }
+    return Ok(board);
+}
+
+/// Writes the placed pieces in the notation (see [`Placement`]),
+/// skipping pieces whose cells do not have the shape of the piece.
+pub fn board_to_notation(board: &GameBoard) -> String {
+    board
+        .to_placements()
+        .iter()
+        .filter_map(|(kind, _)| Placement::from_piece(&board.get_pieces()[kind]))
+        .map(|placement| placement.to_string())
+        .collect::<Vec<String>>()
+        .join("; ")
+}
+
+/// Parses a cell label like `B2` to co-ordinates (on the board of the game).
+pub fn parse_cell(text: &str) -> Result<(usize, usize), String> {
+    let text = text.trim().to_uppercase();
+    let valid = text.len() == 2
+        && text.chars().next().is_some_and(|c| ('A'..='F').contains(&c))
+        && text.chars().nth(1).is_some_and(|c| ('1'..='6').contains(&c));
+    if !valid {
+        return Err(format!("Invalid cell {text}."));
+    }
+    return Ok(Die::from_string(&text).to_coords());
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+/// Parses a cell label (see [`GridGeometry::label`]) on the board of the blocking piece.
+fn parse_cell_on(text: &str, block: &Piece) -> Result<(usize, usize), String> {
+    let positions = block.get_positions();
+    let (m, n) = positions.get_shape();
+    return positions
+        .get_geometry()
+        .parse_label(text)
+        .filter(|&(i, j)| i < m && j < n)
+        .ok_or_else(|| format!("Invalid cell {}.", text.trim().to_uppercase()));
+}
@@ -0,0 +1,7 @@
+/// Models for handling game board
+
+pub mod methods;
+pub mod models;
+
+#[cfg(test)]
+pub mod tests_models;
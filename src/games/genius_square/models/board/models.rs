@@ -0,0 +1,464 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use ndarray::Array2;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result;
+use std::collections::HashMap;
+
+use crate::games::genius_square::models::constants::enums::*;
+use crate::games::genius_square::models::geometry::models::GridGeometry;
+use crate::games::genius_square::models::pieces::models::*;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Blocked cells and placed pieces, with a history of the moves of a player
+/// (see [`GameBoard::place_piece`], [`GameBoard::undo`]).
+#[derive(Clone, Debug)]
+pub struct GameBoard {
+    block: Piece,
+    pieces: HashMap<EnumPiece, Piece>,
+    /// moves which can be undone (last move last)
+    done: Vec<Move>,
+    /// moves which can be redone (last undone move last)
+    undone: Vec<Move>,
+    /// every change of the board in order
+    events: Vec<BoardEvent>,
+}
+
+/// A change of the pieces on the board.
+#[derive(Clone, Debug)]
+pub enum Move {
+    Place(Piece),
+    Remove(Piece),
+    /// the piece on the board followed by the piece replacing it
+    Replace(Piece, Piece),
+}
+
+/// Entry of the event stream of a board (see [`GameBoard::get_events`]).
+#[derive(Clone, Debug)]
+pub enum BoardEvent {
+    Done(Move),
+    /// the move which was undone (the board changed by its inverse)
+    Undone(Move),
+    Redone(Move),
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl GameBoard {
+    pub fn new(block: &Piece) -> Self {
+        let pieces: HashMap<EnumPiece, Piece> = HashMap::new();
+        return Self {block: block.clone(), pieces, done: vec![], undone: vec![], events: vec![]}
+    }
+
+    /// Adds a piece without checks and without recording a move (as used by the solvers).
+    ///
+    /// NOTE: Panics if a piece of the kind is already on the board,
+    /// as replacing a piece has to be explicit (see [`GameBoard::replace_piece`]).
+    #[allow(unused)]
+    pub(crate) fn add_piece(&mut self, symb: &EnumPiece, piece: &Piece) {
+        let previous = self.pieces.insert(symb.clone(), piece.clone());
+        assert!(previous.is_none(), "piece {} is already on the board", symb.as_str());
+    }
+
+    /// Sets all pieces at once, without recording a move.
+    ///
+    /// NOTE: The moves so far can no longer be undone or redone afterwards.
+    #[allow(unused)]
+    pub(crate) fn set_pieces(&mut self, pieces: &HashMap<EnumPiece, Piece>) {
+        self.pieces = pieces.clone();
+        self.done.clear();
+        self.undone.clear();
+    }
+
+    /// Places a piece which is not on the board yet,
+    /// provided it does not cover occupied cells, and records the move.
+    pub fn place_piece(&mut self, piece: &Piece) -> std::result::Result<(), String> {
+        let kind = piece.get_kind();
+        if !ENUM_PIECES.contains(&kind) {
+            return Err(format!("{} cannot be placed.", kind.name()));
+        }
+        if self.pieces.contains_key(&kind) {
+            return Err(format!("Piece {} is already on the board.", kind.as_str()));
+        }
+        self.check_free(piece, None)?;
+        self.perform(Move::Place(piece.clone()));
+        return Ok(());
+    }
+
+    /// Removes a piece from the board and records the move.
+    pub fn remove_piece(&mut self, kind: &EnumPiece) -> std::result::Result<Piece, String> {
+        let piece = self
+            .pieces
+            .get(kind)
+            .cloned()
+            .ok_or_else(|| format!("Piece {} is not on the board.", kind.as_str()))?;
+        self.perform(Move::Remove(piece.clone()));
+        return Ok(piece);
+    }
+
+    /// Moves a piece which is on the board to new cells,
+    /// provided they are not occupied by other pieces, and records the move.
+    /// Returns the piece which was replaced.
+    pub fn replace_piece(&mut self, piece: &Piece) -> std::result::Result<Piece, String> {
+        let kind = piece.get_kind();
+        let old = self
+            .pieces
+            .get(&kind)
+            .cloned()
+            .ok_or_else(|| format!("Piece {} is not on the board.", kind.as_str()))?;
+        self.check_free(piece, Some(&kind))?;
+        self.perform(Move::Replace(old.clone(), piece.clone()));
+        return Ok(old);
+    }
+
+    /// Reverts the last move (if any).
+    pub fn undo(&mut self) -> Option<Move> {
+        let action = self.done.pop()?;
+        self.apply(&action.inverse());
+        self.undone.push(action.clone());
+        self.events.push(BoardEvent::Undone(action.clone()));
+        return Some(action);
+    }
+
+    /// Repeats the last undone move (if any).
+    /// Any new move discards the moves which can be redone.
+    pub fn redo(&mut self) -> Option<Move> {
+        let action = self.undone.pop()?;
+        self.apply(&action);
+        self.done.push(action.clone());
+        self.events.push(BoardEvent::Redone(action.clone()));
+        return Some(action);
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.done.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    /// Moves which led to the current board (first move first)
+    pub fn get_history(&self) -> &[Move] {
+        &self.done
+    }
+
+    /// Every change of the board in order, including undos and redos.
+    /// Consumers can follow the stream by remembering how many events they have seen
+    /// (see [`GameBoard::get_events_since`]).
+    pub fn get_events(&self) -> &[BoardEvent] {
+        &self.events
+    }
+
+    /// Events after the first `n` events
+    pub fn get_events_since(&self, n: usize) -> &[BoardEvent] {
+        &self.events[n.min(self.events.len())..]
+    }
+
+    pub fn get_block(&self) -> &Piece {
+        &self.block
+    }
+
+    pub fn get_pieces(&self) -> &HashMap<EnumPiece, Piece> {
+        &self.pieces
+    }
+
+    /// Shape of the array of the board (determined by the blocking piece)
+    pub fn get_shape(&self) -> (usize, usize) {
+        self.block.get_positions().get_shape()
+    }
+
+    /// Piece of a kind (in its initial orientation) on an array of the shape of the board
+    pub fn create_piece(&self, kind: &EnumPiece) -> Piece {
+        let positions = kind.get_positions();
+        if positions.get_shape() == self.get_shape() {
+            return Piece::from_kind(kind, None);
+        }
+        let (m, n) = self.get_shape();
+        return Piece::from_coords_on(positions.get_geometry(), positions.to_coords(), m, n, Some(kind.clone()));
+    }
+
+    pub fn to_string(&self) -> String {
+        let field = self.to_array_of_strings(false);
+        let text = Self::array_to_string(&field);
+        return text;
+    }
+
+    /// Rows of the board as plain piece symbols
+    pub fn to_rows(&self) -> Vec<String> {
+        self.to_array_of_strings(false)
+            .rows()
+            .into_iter()
+            .map(|row| row.iter().map(|s| s.as_str()).collect::<String>())
+            .collect()
+    }
+
+    /// Cells (e.g. `B2`) covered by each placed piece
+    pub fn to_placements(&self) -> Vec<(EnumPiece, Vec<String>)> {
+        ENUM_PIECES
+            .iter()
+            .filter_map(|kind| {
+                let piece = self.pieces.get(kind)?;
+                let geometry = piece.get_positions().get_geometry();
+                let cells = piece.to_coords().iter().map(|&cell| geometry.label(cell)).collect();
+                Some((kind.clone(), cells))
+            })
+            .collect()
+    }
+
+    /// Codes (see [`EnumPiece::code`]) of the contents of each cell
+    pub fn to_codes(&self) -> Array2<u8> {
+        let (m, n) = self.get_shape();
+        let mut codes = Array2::<u8>::zeros((m, n));
+        for (i, j) in self.block.to_coords() {
+            codes[[i, j]] = EnumPiece::Block.code();
+        }
+        for (kind, piece) in self.pieces.iter() {
+            for (i, j) in piece.to_coords() {
+                codes[[i, j]] = kind.code();
+            }
+        }
+        return codes;
+    }
+
+    #[allow(unused)]
+    pub fn to_formatted(&self) -> String {
+        let field = self.to_array_of_strings(true);
+        let text = Self::array_to_string(&field);
+        return text;
+    }
+
+    pub fn pretty(&self) -> String {
+        let field = self.to_array_of_strings(true);
+        return Self::pretty_field(&field, self.block.get_positions().get_geometry());
+    }
+
+    /// Draws the frame of [`GameBoard::pretty`] around an array of cells,
+    /// each of which has to be displayed as a single character,
+    /// with the columns and rows labelled as in the geometry of the grid.
+    pub fn pretty_field(field: &Array2<String>, geometry: &dyn GridGeometry) -> String {
+        let (m, n) = field.dim();
+
+        // column letters and row numbers of the labels (e.g. `B` and `2` for `B2`)
+        let split_label = |cell: (usize, usize)| {
+            let label = geometry.label(cell);
+            let k = label.find(|c: char| c.is_ascii_digit()).unwrap_or(label.len());
+            let row: String = label[k..].chars().take_while(|c| c.is_ascii_digit()).collect();
+            (label[..k].to_string(), row)
+        };
+        let columns: Vec<String> = (0..n).map(|j| split_label((0, j)).0).collect();
+        let rows: Vec<String> = (0..m).map(|i| split_label((i, 0)).1).collect();
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(1);
+
+        let create_border = |lcorner1: &str, fill1: &str, lcorner2: &str, fill2: &str, mid2: &str, rcorner: &str| {
+            let left = fill1.repeat(width + 2);
+            let middle = format!("{fill2}{mid2}{fill2}{fill2}").repeat(n-1);
+            format!("{lcorner1}{left}{lcorner2}{fill2}{fill2}{middle}{fill2}{rcorner}")
+        };
+
+        let top1 = create_border("\u{02554}", "\u{2550}", "\u{02566}", "\u{2550}", "\u{2564}", "\u{2555}");
+        let top2 = create_border("\u{02560}", "\u{2550}", "\u{0256C}", "\u{2550}", "\u{256A}", "\u{2561}");
+        let mid = create_border("\u{02560}", "\u{2500}", "\u{0256C}", "\u{2500}", "\u{253C}", "\u{2524}");
+        let bot = create_border("\u{02559}", "\u{2500}", "\u{02568}", "\u{2500}", "\u{02534}", "\u{02518}");
+
+        let head = columns
+            .iter()
+            .map(|column| format!("\x1b[91,1m{column}\x1b[0m"))
+            .collect::<Vec<String>>()
+            .join(" \u{2502} ");
+        let corner = " ".repeat(width + 2);
+        let head = format!("{top1}\n\u{02551}{corner}\u{02551} {head} \u{2502}\n{top2}");
+
+        let middle = field.rows()
+            .into_iter()
+            .zip(rows.iter())
+            .map(|(row, index)| {
+                let index = format!("\x1b[91,1m{index:>width$}\x1b[0m");
+                let line = row.iter().map(|s| s.to_string()).collect::<Vec<String>>().join(" \u{2502} ");
+                return format!("\u{02551} {index} \u{02551} {line} \u{2502}");
+            })
+            .collect::<Vec<String>>()
+            .join(format!("\n{mid}\n").as_str());
+
+        let text = format!("{head}\n{middle}\n{bot}");
+
+        return text;
+    }
+
+    /// Draws boards (see [`GameBoard::pretty`]) next to each other.
+    pub fn pretty_side_by_side(boards: &[GameBoard]) -> String {
+        let drawings: Vec<String> = boards.iter().map(|board| board.pretty()).collect();
+        let num_lines = drawings.iter().map(|text| text.lines().count()).max().unwrap_or(0);
+        let text = (0..num_lines)
+            .map(|k| {
+                // NOTE: all lines of a drawing have the same width
+                drawings
+                    .iter()
+                    .map(|text| text.lines().nth(k).unwrap_or(""))
+                    .collect::<Vec<&str>>()
+                    .join("   ")
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        return text;
+    }
+
+    /// Records and applies a new move.
+    fn perform(&mut self, action: Move) {
+        self.apply(&action);
+        self.undone.clear();
+        self.done.push(action.clone());
+        self.events.push(BoardEvent::Done(action));
+    }
+
+    fn apply(&mut self, action: &Move) {
+        match action {
+            Move::Place(piece) | Move::Replace(_, piece) => {
+                self.pieces.insert(piece.get_kind(), piece.clone());
+            },
+            Move::Remove(piece) => {
+                self.pieces.remove(&piece.get_kind());
+            },
+        }
+    }
+
+    /// Checks that the piece covers no blocked cells and no other pieces
+    /// (ignoring the piece of the given kind).
+    fn check_free(&self, piece: &Piece, ignore: Option<&EnumPiece>) -> std::result::Result<(), String> {
+        let positions = piece.get_positions();
+        if positions.intersects(self.block.get_positions()) {
+            return Err(format!("Piece {} covers a blocked cell.", piece.get_symb()));
+        }
+        for (kind, other) in self.pieces.iter() {
+            if Some(kind) == ignore {
+                continue;
+            }
+            if positions.intersects(other.get_positions()) {
+                return Err(format!("Piece {} overlaps piece {}.", piece.get_symb(), kind.as_str()));
+            }
+        }
+        return Ok(());
+    }
+
+    fn to_array_of_strings(&self, formatted: bool) -> Array2<String> {
+        let (m, n) = self.get_shape();
+        let mut trace: Array2<String> = Array2::from_elem((m, n), " ".to_string());
+        let piece = self.get_block();
+        for (i, j) in piece.to_coords() {
+            let alpha = if formatted { piece.get_symb_fmt() } else { piece.get_symb() };
+            trace[[i, j]] = alpha;
+        }
+        for (_, piece) in self.pieces.iter() {
+            for (i, j) in piece.to_coords() {
+                let alpha = if formatted { piece.get_symb_fmt() } else { piece.get_symb() };
+                trace[[i, j]] = alpha;
+            }
+        }
+        return trace;
+    }
+
+    fn array_to_string(field: &Array2<String>) -> String {
+        let n = field.ncols();
+        let hbar = "\u{2500}".repeat(n + 2);
+        let top = format!("\u{250C}{hbar}\u{2510}");
+        let bot = format!("\u{2514}{hbar}\u{2518}");
+        let middle = field.rows()
+            .into_iter()
+            .map(|row| {
+                let line = row.iter().map(|s| s.as_str()).collect::<String>();
+                return format!("\u{2502} {line} \u{2502}");
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        let text = format!("{top}\n{middle}\n{bot}");
+        return text;
+    }
+
+    /// Determines all possible configurations
+    /// of the same piece subject to
+    ///
+    ///  - rotations,
+    /// - v- and h-flips,
+    /// - v- and h-shifts
+    ///
+    /// provided the moves preserve the "weight" of the shadow in the array
+    /// and provided
+    ///
+    /// - no collisions occur with already placed pieces (marked by `obst`)
+    /// - the piece is not adjacent to certain other pieces.
+    ///
+    /// Each configuration occurs once, as only distinct orientations are tried
+    /// (see `BinArray::get_distinct_symmetries`).
+    pub fn get_configurations(
+        &self,
+        piece: &Piece,
+        obst: &Piece,
+    ) -> impl Iterator<Item = Piece> {
+        let it = piece
+            // convert to positions
+            .get_positions()
+            // get all possible orientations + shifts which do not collide with obstacle
+            .get_configurations(Some(obst.get_positions()))
+            // skip all moves which lead to forbidden adjacent pieces
+            .filter(|pos| {
+                // only need to check for collisions of pieces of a paritcular kind
+                let kind = piece.get_kind();
+                if !(NON_ADJACENT.contains(&kind)) {
+                    return true;
+                }
+                let pos_dither = pos.transform_dither();
+                for (s, q) in self.pieces.iter() {
+                    // only need to check for collisions of pieces of a paritcular kind
+                    if !(NON_ADJACENT.contains(s)) {
+                        continue;
+                    }
+                    if *s == kind {
+                        continue;
+                    }
+
+                    if pos_dither.intersects(q.get_positions()) {
+                        return false;
+                    }
+                }
+                return true;
+            })
+            // convert to piece
+            .map(|pos| {
+                let kind = piece.get_kind();
+                Piece::from_kind(&kind, Some(pos))
+            });
+        return it;
+    }
+}
+
+impl Move {
+    pub fn get_kind(&self) -> EnumPiece {
+        match self {
+            Move::Place(piece) | Move::Remove(piece) | Move::Replace(_, piece) => piece.get_kind(),
+        }
+    }
+
+    /// The move which reverts the move.
+    pub fn inverse(&self) -> Self {
+        match self {
+            Move::Place(piece) => Move::Remove(piece.clone()),
+            Move::Remove(piece) => Move::Place(piece.clone()),
+            Move::Replace(old, new) => Move::Replace(new.clone(), old.clone()),
+        }
+    }
+}
+
+impl Display for GameBoard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.to_string())
+    }
+}
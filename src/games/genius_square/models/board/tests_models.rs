@@ -0,0 +1,162 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use super::models::*;
+use crate::_core::strings::purify_string;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::pieces::models::Piece;
+use crate::games::genius_square::models::geometry::models::TRI_GRID;
+use crate::games::genius_square::tests_support::create_board;
+use crate::games::genius_square::tests_support::embed_board;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undo_redo() {
+        let mut board = create_board("");
+        let x = Piece::from_coords(vec![(1, 1), (1, 2), (2, 1), (2, 2)], Some(EnumPiece::X));
+        let x_moved = Piece::from_coords(vec![(3, 3), (3, 4), (4, 3), (4, 4)], Some(EnumPiece::X));
+        let single = Piece::from_coords(vec![(0, 0)], Some(EnumPiece::Symb1));
+
+        board.place_piece(&x).unwrap();
+        board.place_piece(&single).unwrap();
+        assert_eq!(board.replace_piece(&x_moved).unwrap().to_coords(), x.to_coords());
+        assert_eq!(board.remove_piece(&EnumPiece::Symb1).unwrap().to_coords(), single.to_coords());
+        assert_eq!(board.get_history().len(), 4);
+        assert_eq!(pieces(&board), vec![(EnumPiece::X, x_moved.to_coords())]);
+
+        // step back to the start
+        assert!(matches!(board.undo(), Some(Move::Remove(_))));
+        assert!(matches!(board.undo(), Some(Move::Replace(_, _))));
+        assert_eq!(pieces(&board), vec![(EnumPiece::Symb1, vec![(0, 0)]), (EnumPiece::X, x.to_coords())]);
+        board.undo();
+        board.undo();
+        assert!(board.undo().is_none());
+        assert!(board.get_pieces().is_empty());
+
+        // and forward again
+        assert!(matches!(board.redo(), Some(Move::Place(_))));
+        assert!(board.can_redo());
+        // a new move discards the moves which can be redone
+        board.place_piece(&single).unwrap();
+        assert!(!board.can_redo());
+        assert!(board.redo().is_none());
+        assert_eq!(board.get_history().len(), 2);
+        // only the pieces of the game can be placed
+        let block = Piece::from_coords(vec![(5, 5)], Some(EnumPiece::Block));
+        assert!(board.place_piece(&block).is_err());
+    }
+
+    #[test]
+    fn test_set_pieces() {
+        let mut board = create_board("");
+        let x = Piece::from_coords(vec![(1, 1), (1, 2), (2, 1), (2, 2)], Some(EnumPiece::X));
+        let single = Piece::from_coords(vec![(0, 0)], Some(EnumPiece::Symb1));
+        board.place_piece(&x).unwrap();
+        board.place_piece(&single).unwrap();
+        board.undo().unwrap();
+
+        // the history is cleared, so that undoing cannot restore other pieces
+        board.set_pieces(&HashMap::from([(EnumPiece::Symb1, single.clone())]));
+        assert!(!board.can_undo() && !board.can_redo());
+        assert!(board.undo().is_none() && board.redo().is_none());
+        assert_eq!(pieces(&board), vec![(EnumPiece::Symb1, vec![(0, 0)])]);
+        board.place_piece(&x).unwrap();
+        board.undo().unwrap();
+        assert_eq!(pieces(&board), vec![(EnumPiece::Symb1, vec![(0, 0)])]);
+    }
+
+    #[test]
+    fn test_invalid_moves() {
+        let mut board = create_board("");
+        let x = Piece::from_coords(vec![(1, 1), (1, 2), (2, 1), (2, 2)], Some(EnumPiece::X));
+        board.place_piece(&x).unwrap();
+        // replacing has to be explicit
+        assert!(board.place_piece(&x).is_err());
+        // B1 is blocked
+        let on_block = Piece::from_coords(vec![(0, 1)], Some(EnumPiece::Symb1));
+        assert!(board.place_piece(&on_block).is_err());
+        let on_x = Piece::from_coords(vec![(1, 1)], Some(EnumPiece::Symb1));
+        assert!(board.place_piece(&on_x).is_err());
+        assert!(board.remove_piece(&EnumPiece::Symb1).is_err());
+        assert!(board.replace_piece(&on_x).is_err());
+        // moving a piece onto its own cells is fine
+        let x_shifted = Piece::from_coords(vec![(2, 1), (2, 2), (3, 1), (3, 2)], Some(EnumPiece::X));
+        assert!(board.replace_piece(&x_shifted).is_err());
+        let x_shifted = Piece::from_coords(vec![(1, 2), (1, 3), (2, 2), (2, 3)], Some(EnumPiece::X));
+        assert!(board.replace_piece(&x_shifted).is_ok());
+        assert_eq!(board.get_history().len(), 2);
+    }
+
+    #[test]
+    fn test_event_stream() {
+        let mut board = create_board("");
+        let single = Piece::from_coords(vec![(0, 0)], Some(EnumPiece::Symb1));
+        board.place_piece(&single).unwrap();
+        let seen = board.get_events().len();
+        board.undo();
+        board.redo();
+        let events = board.get_events_since(seen);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], BoardEvent::Undone(Move::Place(_))));
+        assert!(matches!(&events[1], BoardEvent::Redone(Move::Place(_))));
+        assert!(board.get_events_since(10).is_empty());
+        // solvers add pieces without recording moves
+        let mut board = create_board("");
+        board.add_piece(&EnumPiece::Symb1, &single);
+        assert!(board.get_events().is_empty() && !board.can_undo());
+    }
+
+    #[test]
+    fn test_pretty() {
+        let board = create_board("X=B2,C2,B3,C3");
+        let text = board.pretty();
+        assert_eq!(text.lines().count(), 3 + 2 * 6);
+        assert!(text.lines().nth(1).unwrap().contains("\x1b[91,1mF\x1b[0m \u{2502}"));
+
+        // rows and columns are labelled on boards of any size
+        let text = embed_board(&board, 12, 12).pretty();
+        assert_eq!(text.lines().count(), 3 + 2 * 12);
+        assert!(text.lines().nth(1).unwrap().contains("\x1b[91,1mL\x1b[0m \u{2502}"));
+        assert!(text.lines().nth(3).unwrap().starts_with("\u{02551} \x1b[91,1m 1\x1b[0m \u{02551}"));
+        assert!(text.lines().nth(25).unwrap().starts_with("\u{02551} \x1b[91,1m12\x1b[0m \u{02551}"));
+        // all lines have the same width
+        let widths: Vec<usize> = text.lines().map(|line| purify_string(&line.to_string()).chars().count()).collect();
+        assert!(widths.iter().all(|&width| width == widths[0]), "{widths:?}");
+
+        // triangles are labelled by the letters of their pairs
+        let block = Piece::from_coords_on(&TRI_GRID, vec![(0, 0)], 2, 4, Some(EnumPiece::Block));
+        let text = GameBoard::new(&block).pretty();
+        assert!(text.lines().nth(1).unwrap().contains("\x1b[91,1mA\x1b[0m \u{2502} \x1b[91,1mA\x1b[0m \u{2502} \x1b[91,1mB\x1b[0m"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_piece_does_not_replace() {
+        let mut board = create_board("");
+        let single = Piece::from_coords(vec![(0, 0)], Some(EnumPiece::Symb1));
+        board.add_piece(&EnumPiece::Symb1, &single);
+        board.add_piece(&EnumPiece::Symb1, &single);
+    }
+
+    /// Placed pieces with their cells, sorted by kind
+    fn pieces(board: &GameBoard) -> Vec<(EnumPiece, Vec<(usize, usize)>)> {
+        let mut result: Vec<(EnumPiece, Vec<(usize, usize)>)> = board
+            .get_pieces()
+            .iter()
+            .map(|(kind, piece)| (kind.clone(), piece.to_coords()))
+            .collect();
+        result.sort_by_key(|(kind, _)| kind.code());
+        return result;
+    }
+}
@@ -0,0 +1,3 @@
+/// Models for encoding game states as SAT problems
+
+pub mod models;
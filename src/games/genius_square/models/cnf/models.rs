@@ -0,0 +1,193 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::constants::enums::ENUM_PIECES;
+use crate::games::genius_square::models::pieces::models::Piece;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Boolean formula in conjunctive normal form.
+///
+/// Variables are numbered from `1`, literals are signed variables
+/// and every clause is a disjunction of literals (as in the DIMACS format).
+#[derive(Clone, Debug, Default)]
+pub struct CnfFormula {
+    num_vars: usize,
+    clauses: Vec<Vec<i64>>,
+    comments: Vec<String>,
+}
+
+/// Encoding of a game state as a SAT problem,
+/// with one variable per placement of a piece.
+#[derive(Clone, Debug)]
+pub struct CnfEncoding {
+    /// state which was encoded
+    board: GameBoard,
+    /// placement of the variable `k + 1`
+    placements: Vec<Piece>,
+    formula: CnfFormula,
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl CnfFormula {
+    pub fn new(num_vars: usize) -> Self {
+        Self {num_vars, clauses: vec![], comments: vec![]}
+    }
+
+    pub fn get_num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    pub fn get_clauses(&self) -> &Vec<Vec<i64>> {
+        &self.clauses
+    }
+
+    /// Adds a comment line to the header of the DIMACS output.
+    pub fn add_comment(&mut self, comment: &str) {
+        self.comments.push(comment.to_string());
+    }
+
+    pub fn add_clause(&mut self, clause: Vec<i64>) {
+        self.clauses.push(clause);
+    }
+
+    /// Adds clauses forcing at most one of the variables to be true (pairwise encoding).
+    pub fn add_at_most_one(&mut self, vars: &[i64]) {
+        for (k, &a) in vars.iter().enumerate() {
+            for &b in vars[k + 1..].iter() {
+                self.add_clause(vec![-a, -b]);
+            }
+        }
+    }
+
+    /// Adds clauses forcing exactly one of the variables to be true.
+    pub fn add_exactly_one(&mut self, vars: &[i64]) {
+        self.add_clause(vars.to_vec());
+        self.add_at_most_one(vars);
+    }
+
+    /// Checks whether the assignment (the set of true variables) satisfies every clause.
+    pub fn is_satisfied_by(&self, true_vars: &HashSet<i64>) -> bool {
+        self.clauses.iter().all(|clause| {
+            clause.iter().any(|&lit| true_vars.contains(&lit.abs()) == (lit > 0))
+        })
+    }
+
+    /// Writes the formula in the DIMACS CNF format.
+    pub fn to_dimacs(&self) -> String {
+        let mut lines: Vec<String> = self.comments.iter().map(|comment| format!("c {comment}")).collect();
+        lines.push(format!("p cnf {} {}", self.num_vars, self.clauses.len()));
+        for clause in self.clauses.iter() {
+            let literals: Vec<String> = clause.iter().map(|lit| lit.to_string()).collect();
+            lines.push(format!("{} 0", literals.join(" ")));
+        }
+        return lines.join("\n") + "\n";
+    }
+}
+
+impl CnfEncoding {
+    pub fn new(board: &GameBoard, placements: Vec<Piece>, formula: CnfFormula) -> Self {
+        Self {board: board.clone(), placements, formula}
+    }
+
+    pub fn get_formula(&self) -> &CnfFormula {
+        &self.formula
+    }
+
+    /// Placement of a variable (numbered from `1`).
+    pub fn get_placement(&self, var: i64) -> Option<&Piece> {
+        let index = usize::try_from(var).ok()?.checked_sub(1)?;
+        return self.placements.get(index);
+    }
+
+    /// Variable of a placement, if the placement occurs in the encoding.
+    pub fn find_var(&self, piece: &Piece) -> Option<i64> {
+        let coords = piece.to_coords();
+        let index = self
+            .placements
+            .iter()
+            .position(|p| p.get_kind() == piece.get_kind() && p.to_coords() == coords)?;
+        return Some(index as i64 + 1);
+    }
+
+    pub fn to_dimacs(&self) -> String {
+        self.formula.to_dimacs()
+    }
+
+    /// Recovers the board from the model written by a SAT solver.
+    ///
+    /// Accepts the output of common solvers, i.e. value lines `v 1 -2 ... 0`
+    /// (with an optional status line `s SATISFIABLE`)
+    /// or plain lines of literals (optionally preceded by `SAT`).
+    /// The model has to place every piece and satisfy the formula.
+    pub fn decode_model(&self, text: &str) -> Result<GameBoard, String> {
+        let true_vars = parse_model(text)?;
+        let mut board = self.board.clone();
+        let mut vars: Vec<&i64> = true_vars.iter().collect();
+        vars.sort();
+        for &var in vars {
+            let piece = self
+                .get_placement(var)
+                .ok_or_else(|| format!("Variable {var} does not occur in the encoding."))?;
+            let kind = piece.get_kind();
+            if board.get_pieces().contains_key(&kind) {
+                return Err(format!("Piece {} is placed more than once.", kind.as_str()));
+            }
+            board.add_piece(&kind, piece);
+        }
+        if let Some(kind) = ENUM_PIECES.iter().find(|kind| !board.get_pieces().contains_key(kind)) {
+            return Err(format!("Piece {} is not placed.", kind.as_str()));
+        }
+        if !self.formula.is_satisfied_by(&true_vars) {
+            return Err("The model does not satisfy the formula.".to_string());
+        }
+        return Ok(board);
+    }
+}
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Parses the model written by a SAT solver (see [`CnfEncoding::decode_model`])
+/// into the set of true variables.
+pub fn parse_model(text: &str) -> Result<HashSet<i64>, String> {
+    let mut true_vars: HashSet<i64> = HashSet::new();
+    for line in text.lines() {
+        let line = line.trim();
+        let status = line.strip_prefix("s ").unwrap_or(line).trim();
+        match status {
+            "UNSAT" | "UNSATISFIABLE" => {
+                return Err("The problem is unsatisfiable.".to_string());
+            },
+            "INDETERMINATE" | "UNKNOWN" => {
+                return Err("The solver did not decide the problem.".to_string());
+            },
+            "SAT" | "SATISFIABLE" => {
+                continue;
+            },
+            _ => {},
+        }
+        if line.is_empty() || line.starts_with('c') || line.starts_with('s') {
+            continue;
+        }
+        let literals = line.strip_prefix('v').unwrap_or(line);
+        for token in literals.split_whitespace() {
+            let lit: i64 = token.parse().map_err(|_| format!("Invalid literal {token}."))?;
+            if lit > 0 {
+                true_vars.insert(lit);
+            }
+        }
+    }
+    return Ok(true_vars);
+}
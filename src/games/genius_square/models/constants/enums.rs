@@ -6,8 +6,9 @@ use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result;
+use std::str::FromStr;
 
-use crate::models::arrays::models::BinArray;
+use crate::games::genius_square::models::arrays::models::BinArray;
 use super::board::*;
 use super::pieces::*;
 
@@ -42,6 +43,21 @@ pub const ENUM_PIECES: &[EnumPiece] = &[
     EnumPiece::Z,
 ];
 
+/// All contents of a cell, indexed by their codes (see [`EnumPiece::code`])
+pub const ENUM_CELLS: &[EnumPiece] = &[
+    EnumPiece::Blank,
+    EnumPiece::Block,
+    EnumPiece::Symb1,
+    EnumPiece::Symb2,
+    EnumPiece::Symb3,
+    EnumPiece::Symb4,
+    EnumPiece::C,
+    EnumPiece::L,
+    EnumPiece::T,
+    EnumPiece::X,
+    EnumPiece::Z,
+];
+
 pub const NON_ADJACENT: &[EnumPiece] = &[
     EnumPiece::Symb1,
     EnumPiece::Symb2,
@@ -88,6 +104,43 @@ impl EnumPiece {
         }
     }
 
+    /// Name of the shape (e.g. `L-tetromino`)
+    pub const fn name(&self) -> &'static str {
+        match self {
+            EnumPiece::Blank => "blank",
+            EnumPiece::Block => "block",
+            EnumPiece::Symb1 => "monomino",
+            EnumPiece::Symb2 => "domino",
+            EnumPiece::Symb3 => "I-tromino",
+            EnumPiece::Symb4 => "I-tetromino",
+            EnumPiece::C => "V-tromino",
+            EnumPiece::L => "L-tetromino",
+            EnumPiece::T => "T-tetromino",
+            EnumPiece::X => "O-tetromino",
+            EnumPiece::Z => "S-tetromino",
+        }
+    }
+
+    /// Iterates over the pieces in [`ENUM_PIECES`].
+    pub fn iter() -> impl Iterator<Item = EnumPiece> {
+        ENUM_PIECES.iter().cloned()
+    }
+
+    /// Recovers a piece from its symbol (see [`EnumPiece::as_str`]).
+    /// Only the pieces in [`ENUM_PIECES`] can be recovered.
+    pub fn from_symb(symb: &str) -> Option<EnumPiece> {
+        ENUM_PIECES
+            .iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(symb))
+            .cloned()
+    }
+
+    /// Numerical code of the cell contents, i.e. the index in [`ENUM_CELLS`].
+    pub fn code(&self) -> u8 {
+        let index = ENUM_CELLS.iter().position(|kind| kind == self).unwrap();
+        return index as u8;
+    }
+
     pub fn get_positions(&self) -> BinArray {
         let raw = match self {
             EnumPiece::Blank => " ",
@@ -122,3 +175,15 @@ impl Display for EnumPiece {
         write!(f, "{}", self.to_formatted())
     }
 }
+
+/// Parses a piece in [`ENUM_PIECES`] from its symbol (e.g. `L`) or its name (e.g. `L-tetromino`).
+impl FromStr for EnumPiece {
+    type Err = String;
+
+    fn from_str(text: &str) -> std::result::Result<Self, Self::Err> {
+        let text = text.trim();
+        EnumPiece::from_symb(text)
+            .or_else(|| EnumPiece::iter().find(|kind| kind.name().eq_ignore_ascii_case(text)))
+            .ok_or_else(|| format!("Unknown piece {text}."))
+    }
+}
@@ -0,0 +1,3 @@
+/// Models for the coverage of cells across the solutions of a game state
+
+pub mod models;
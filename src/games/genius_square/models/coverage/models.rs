@@ -0,0 +1,233 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use ndarray::Array2;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::constants::enums::ENUM_CELLS;
+use crate::games::genius_square::models::constants::enums::ENUM_PIECES;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::dice::models::Die;
+use crate::games::genius_square::models::geometry::models::SQUARE_GRID;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+/// Background colours (ANSI 256) from rarely to often covered
+const HEAT_COLOURS: &[u8] = &[236, 238, 240, 242, 244, 246, 248, 250];
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Number of solutions of a game state in which each piece covers each cell.
+#[derive(Clone, Debug)]
+pub struct Coverage {
+    /// state whose solutions are recorded
+    board: GameBoard,
+    num_solutions: u64,
+    counts: HashMap<EnumPiece, Array2<u64>>,
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl Coverage {
+    pub fn new(board: &GameBoard) -> Self {
+        let shape = board.get_shape();
+        let counts = ENUM_PIECES
+            .iter()
+            .map(|kind| (kind.clone(), Array2::<u64>::zeros(shape)))
+            .collect();
+        return Self {board: board.clone(), num_solutions: 0, counts};
+    }
+
+    /// Records the pieces of a solution of the state.
+    pub fn add_solution(&mut self, solution: &GameBoard) {
+        self.num_solutions += 1;
+        for (kind, piece) in solution.get_pieces() {
+            let Some(counts) = self.counts.get_mut(kind) else {
+                continue;
+            };
+            for (i, j) in piece.to_coords() {
+                counts[[i, j]] += 1;
+            }
+        }
+    }
+
+    pub fn get_board(&self) -> &GameBoard {
+        &self.board
+    }
+
+    pub fn get_num_solutions(&self) -> u64 {
+        self.num_solutions
+    }
+
+    /// Number of solutions in which the piece covers the cell
+    pub fn get_count(&self, kind: &EnumPiece, cell: (usize, usize)) -> u64 {
+        let (i, j) = cell;
+        self.counts.get(kind).map_or(0, |counts| counts[[i, j]])
+    }
+
+    /// Fraction of the solutions in which the piece covers the cell
+    pub fn get_share(&self, kind: &EnumPiece, cell: (usize, usize)) -> f64 {
+        if self.num_solutions == 0 {
+            return 0.;
+        }
+        return self.get_count(kind, cell) as f64 / self.num_solutions as f64;
+    }
+
+    /// The piece which covers the cell most often (if any) and its count
+    pub fn get_dominant(&self, cell: (usize, usize)) -> Option<(EnumPiece, u64)> {
+        ENUM_PIECES
+            .iter()
+            .map(|kind| (kind.clone(), self.get_count(kind, cell)))
+            .filter(|&(_, count)| count > 0)
+            .max_by_key(|&(_, count)| count)
+    }
+
+    /// The piece which covers the cell in every solution (if there are solutions)
+    pub fn get_forced(&self, cell: (usize, usize)) -> Option<EnumPiece> {
+        if self.num_solutions == 0 {
+            return None;
+        }
+        let (kind, count) = self.get_dominant(cell)?;
+        return if count == self.num_solutions { Some(kind) } else { None };
+    }
+
+    /// Cells (e.g. `B2`) which are covered by the same piece in every solution,
+    /// grouped by piece
+    pub fn get_forced_placements(&self) -> Vec<(EnumPiece, Vec<String>)> {
+        let free = self.free_cells();
+        ENUM_PIECES
+            .iter()
+            .filter_map(|kind| {
+                let cells: Vec<String> = free
+                    .iter()
+                    .filter(|&&cell| self.get_forced(cell).as_ref() == Some(kind))
+                    .map(|&(i, j)| Die::from_coords(i, j).to_string())
+                    .collect();
+                if cells.is_empty() {
+                    return None;
+                }
+                Some((kind.clone(), cells))
+            })
+            .collect()
+    }
+
+    /// Heat grid showing the piece which covers each free cell most often,
+    /// shaded by how often it does so.
+    /// Cells with a forced piece are highlighted.
+    pub fn pretty(&self) -> String {
+        let field = self.to_field(|cell| {
+            let (kind, count) = self.get_dominant(cell)?;
+            let text = kind.as_str().to_string();
+            if count == self.num_solutions {
+                return Some(format!("\x1b[1;7m{text}\x1b[0m"));
+            }
+            return Some(shade(&text, count as f64 / self.num_solutions as f64));
+        });
+        return GameBoard::pretty_field(&field, &SQUARE_GRID);
+    }
+
+    /// Heat grid showing how often a piece covers each free cell,
+    /// in tenths of the solutions (`*` if always).
+    pub fn pretty_piece(&self, kind: &EnumPiece) -> String {
+        let field = self.to_field(|cell| {
+            let count = self.get_count(kind, cell);
+            if count == 0 {
+                return None;
+            }
+            if count == self.num_solutions {
+                return Some("\x1b[1;7m*\x1b[0m".to_string());
+            }
+            let share = count as f64 / self.num_solutions as f64;
+            let tenths = ((share * 10.).round() as u64).clamp(1, 9);
+            return Some(shade(&tenths.to_string(), share));
+        });
+        return GameBoard::pretty_field(&field, &SQUARE_GRID);
+    }
+
+    /// Counts per cell and piece, and the forced placements, e.g.
+    ///
+    /// ```json
+    /// {
+    ///     "solutions": 346,
+    ///     "blocks": ["B1", ...],
+    ///     "cells": {"A1": {"1": 120, "4": 226}, ...},
+    ///     "forced": {"X": ["B2", ...]}
+    /// }
+    /// ```
+    pub fn to_json(&self) -> Value {
+        let blocks: Vec<String> = self
+            .board
+            .get_block()
+            .to_coords()
+            .iter()
+            .map(|&(i, j)| Die::from_coords(i, j).to_string())
+            .collect();
+        let mut cells = Map::new();
+        for cell in self.free_cells() {
+            let mut counts = Map::new();
+            for kind in ENUM_PIECES {
+                let count = self.get_count(kind, cell);
+                if count > 0 {
+                    counts.insert(kind.as_str().to_string(), json!(count));
+                }
+            }
+            let (i, j) = cell;
+            cells.insert(Die::from_coords(i, j).to_string(), Value::Object(counts));
+        }
+        let forced: Map<String, Value> = self
+            .get_forced_placements()
+            .into_iter()
+            .map(|(kind, cells)| (kind.as_str().to_string(), json!(cells)))
+            .collect();
+        return json!({
+            "solutions": self.num_solutions,
+            "blocks": blocks,
+            "cells": cells,
+            "forced": forced,
+        });
+    }
+
+    /// Cells which are neither blocked nor covered by pieces of the state
+    fn free_cells(&self) -> Vec<(usize, usize)> {
+        let codes = self.board.to_codes();
+        codes
+            .indexed_iter()
+            .filter(|&(_, &code)| code == EnumPiece::Blank.code())
+            .map(|(cell, _)| cell)
+            .collect()
+    }
+
+    /// Cells of the state, with the free cells rendered by the given function
+    fn to_field(&self, render: impl Fn((usize, usize)) -> Option<String>) -> Array2<String> {
+        let codes = self.board.to_codes();
+        return Array2::from_shape_fn(codes.dim(), |cell| {
+            let code = codes[cell];
+            if code != EnumPiece::Blank.code() {
+                return ENUM_CELLS[code as usize].to_formatted().to_string();
+            }
+            return render(cell).unwrap_or_else(|| " ".to_string());
+        });
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+/// Text on a background whose brightness increases with the share
+fn shade(text: &str, share: f64) -> String {
+    let k = ((share * HEAT_COLOURS.len() as f64) as usize).min(HEAT_COLOURS.len() - 1);
+    return format!("\x1b[48;5;{}m{text}\x1b[0m", HEAT_COLOURS[k]);
+}
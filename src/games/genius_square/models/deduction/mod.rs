@@ -0,0 +1,3 @@
+/// Models for the logical deductions made before and during the search
+
+pub mod models;
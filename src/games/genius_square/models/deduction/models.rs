@@ -0,0 +1,89 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result;
+
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::dice::models::Die;
+use crate::games::genius_square::models::pieces::models::Piece;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// A placement which every solution has to contain.
+#[derive(Clone, Debug)]
+pub struct Deduction {
+    pub piece: Piece,
+    pub reason: DeductionReason,
+}
+
+/// Why a placement is forced
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeductionReason {
+    /// The placement is the only one which covers the free cell.
+    OnlyCover((usize, usize)),
+    /// The placement is the only legal one of the piece.
+    OnlyPlacement,
+}
+
+/// Reason why a game state has no solution
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Contradiction {
+    /// No remaining piece fits into the free cell.
+    UncoverableCell((usize, usize)),
+    /// The piece has no legal placement left.
+    UnplaceablePiece(EnumPiece),
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl Deduction {
+    pub fn new(piece: &Piece, reason: DeductionReason) -> Self {
+        Self {piece: piece.clone(), reason}
+    }
+
+    /// Cells (e.g. `B2`) covered by the forced placement
+    pub fn get_cells(&self) -> Vec<String> {
+        self.piece
+            .to_coords()
+            .iter()
+            .map(|&(i, j)| Die::from_coords(i, j).to_string())
+            .collect()
+    }
+}
+
+impl Display for Deduction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let kind = self.piece.get_kind();
+        let cells = self.get_cells().join(", ");
+        match self.reason {
+            DeductionReason::OnlyCover((i, j)) => {
+                let cell = Die::from_coords(i, j);
+                write!(f, "{cell} can only be covered by {} on {cells}.", kind.as_str())
+            },
+            DeductionReason::OnlyPlacement => {
+                write!(f, "{} only fits on {cells}.", kind.as_str())
+            },
+        }
+    }
+}
+
+impl Display for Contradiction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Contradiction::UncoverableCell((i, j)) => {
+                write!(f, "No remaining piece fits into {}.", Die::from_coords(*i, *j))
+            },
+            Contradiction::UnplaceablePiece(kind) => {
+                write!(f, "{} does not fit anywhere.", kind.as_str())
+            },
+        }
+    }
+}
@@ -0,0 +1,278 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use itertools::Itertools;
+use ndarray::Array2;
+use rand::prelude::IndexedRandom;
+use rand_chacha::ChaCha8Rng;
+use std::fmt::Debug;
+
+use crate::games::genius_square::models::board::methods::parse_cell;
+use crate::games::genius_square::models::constants::board::GRID_HEIGHT;
+use crate::games::genius_square::models::constants::board::GRID_WIDTH;
+use crate::games::genius_square::models::constants::dice::DICE;
+use crate::games::genius_square::models::dice::models::Die;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+const NUM_CELLS: usize = GRID_HEIGHT * GRID_WIDTH;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Exact probabilities of blocked cells for a set of fair dice
+/// (by default [`DICE`]).
+///
+/// Cells are indexed row by row, i.e. `(i, j)` has the index `i * GRID_WIDTH + j`.
+#[derive(Clone, Debug)]
+pub struct DiceAnalysis {
+    dice: Vec<Vec<String>>,
+    /// probability of each cell per die
+    face_probabilities: Vec<[f64; NUM_CELLS]>,
+}
+
+/// Odds of a face which occurs more than once on a die.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FaceSkew {
+    /// index of the die (from `0`)
+    pub die: usize,
+    pub face: String,
+    /// number of faces of the die showing the cell
+    pub multiplicity: usize,
+    pub probability: f64,
+    /// probability if every distinct cell of the die were equally likely
+    pub probability_unskewed: f64,
+}
+
+/// Comparison of sampled and exact probabilities.
+#[derive(Clone, Debug)]
+pub struct MonteCarloReport {
+    pub num_rolls: usize,
+    /// sampled probability of each cell being blocked
+    pub cell_frequencies: Array2<f64>,
+    /// largest deviation from the exact probabilities of single cells
+    pub max_cell_error: f64,
+    /// largest deviation from the exact joint probabilities of pairs of cells
+    pub max_joint_error: f64,
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl DiceAnalysis {
+    /// Analysis of the dice of the game.
+    pub fn new() -> Self {
+        let dice = DICE
+            .iter()
+            .map(|die| die.iter().map(|face| face.to_string()).collect())
+            .collect();
+        return Self::from_dice(dice).unwrap();
+    }
+
+    /// Analysis of custom dice, whose faces are cells like `B2`.
+    pub fn from_dice(dice: Vec<Vec<String>>) -> Result<Self, String> {
+        let mut face_probabilities = vec![];
+        let mut dice_ = vec![];
+        for (k, die) in dice.iter().enumerate() {
+            if die.is_empty() {
+                return Err(format!("Die {} has no faces.", k + 1));
+            }
+            let mut counts = [0usize; NUM_CELLS];
+            let mut faces = vec![];
+            for face in die.iter() {
+                let cell = parse_cell(face)?;
+                counts[to_index(cell)] += 1;
+                faces.push(to_label(cell));
+            }
+            face_probabilities.push(counts.map(|count| count as f64 / die.len() as f64));
+            dice_.push(faces);
+        }
+        return Ok(Self {dice: dice_, face_probabilities});
+    }
+
+    /// Replaces the faces of a die (indexed from `0`),
+    /// e.g. to study the effect of new dice on the balance of the game.
+    pub fn with_die(&self, k: usize, faces: Vec<String>) -> Result<Self, String> {
+        if k >= self.dice.len() {
+            return Err(format!("There is no die {}.", k + 1));
+        }
+        let mut dice = self.dice.clone();
+        dice[k] = faces;
+        return Self::from_dice(dice);
+    }
+
+    pub fn get_dice(&self) -> &Vec<Vec<String>> {
+        &self.dice
+    }
+
+    /// Probability of the cell being blocked by at least one die
+    pub fn get_cell_probability(&self, cell: (usize, usize)) -> f64 {
+        let index = to_index(cell);
+        let p_free: f64 = self.face_probabilities.iter().map(|p| 1. - p[index]).product();
+        return 1. - p_free;
+    }
+
+    /// Probabilities of all cells being blocked
+    pub fn get_cell_probabilities(&self) -> Array2<f64> {
+        Array2::from_shape_fn((GRID_HEIGHT, GRID_WIDTH), |cell| self.get_cell_probability(cell))
+    }
+
+    /// Probability of both cells being blocked
+    pub fn get_joint_probability(&self, cell1: (usize, usize), cell2: (usize, usize)) -> f64 {
+        if cell1 == cell2 {
+            return self.get_cell_probability(cell1);
+        }
+        // inclusion-exclusion, using that a die shows only one cell
+        let (a, b) = (to_index(cell1), to_index(cell2));
+        let p_neither: f64 = self.face_probabilities.iter().map(|p| 1. - p[a] - p[b]).product();
+        let p_either = 1. - p_neither;
+        return self.get_cell_probability(cell1) + self.get_cell_probability(cell2) - p_either;
+    }
+
+    /// Joint probabilities of all pairs of cells (indexed row by row),
+    /// with the probabilities of single cells on the diagonal
+    pub fn get_joint_probabilities(&self) -> Array2<f64> {
+        Array2::from_shape_fn((NUM_CELLS, NUM_CELLS), |(a, b)| {
+            self.get_joint_probability(to_cell(a), to_cell(b))
+        })
+    }
+
+    /// Joint probability minus the probability if the cells were blocked independently.
+    /// This is negative for cells on the same die.
+    pub fn get_covariance(&self, cell1: (usize, usize), cell2: (usize, usize)) -> f64 {
+        let p1 = self.get_cell_probability(cell1);
+        let p2 = self.get_cell_probability(cell2);
+        return self.get_joint_probability(cell1, cell2) - p1 * p2;
+    }
+
+    /// Joint probabilities and covariances of all (unordered) pairs of distinct cells
+    pub fn get_pairs(&self) -> Vec<(String, String, f64, f64)> {
+        (0..NUM_CELLS)
+            .tuple_combinations()
+            .map(|(a, b)| {
+                let (cell1, cell2) = (to_cell(a), to_cell(b));
+                let joint = self.get_joint_probability(cell1, cell2);
+                let covariance = self.get_covariance(cell1, cell2);
+                (to_label(cell1), to_label(cell2), joint, covariance)
+            })
+            .collect()
+    }
+
+    /// Cells (e.g. `B2`) which no die can block
+    pub fn get_never_blocked(&self) -> Vec<String> {
+        (0..NUM_CELLS)
+            .filter(|&index| self.face_probabilities.iter().all(|p| p[index] == 0.))
+            .map(|index| to_label(to_cell(index)))
+            .collect()
+    }
+
+    /// Faces which occur more than once on their die
+    pub fn get_skews(&self) -> Vec<FaceSkew> {
+        let mut skews = vec![];
+        for (k, die) in self.dice.iter().enumerate() {
+            let num_distinct = die.iter().unique().count();
+            for (face, multiplicity) in die.iter().counts().into_iter().sorted() {
+                if multiplicity < 2 {
+                    continue;
+                }
+                skews.push(FaceSkew {
+                    die: k,
+                    face: face.clone(),
+                    multiplicity,
+                    probability: multiplicity as f64 / die.len() as f64,
+                    probability_unskewed: 1. / num_distinct as f64,
+                });
+            }
+        }
+        return skews;
+    }
+
+    /// Rolls the dice.
+    pub fn roll(&self, rng: &mut ChaCha8Rng) -> Vec<String> {
+        self.dice
+            .iter()
+            .map(|die| die.choose(rng).unwrap().clone())
+            .collect()
+    }
+
+    /// Samples rolls and compares the frequencies with the exact probabilities.
+    pub fn monte_carlo(&self, num_rolls: usize, rng: &mut ChaCha8Rng) -> MonteCarloReport {
+        let mut counts = Array2::<u64>::zeros((NUM_CELLS, NUM_CELLS));
+        for _ in 0..num_rolls {
+            let indices: Vec<usize> = self
+                .roll(rng)
+                .iter()
+                .map(|face| to_index(Die::from_string(face).to_coords()))
+                .unique()
+                .collect();
+            for &a in indices.iter() {
+                for &b in indices.iter() {
+                    counts[[a, b]] += 1;
+                }
+            }
+        }
+
+        let n = num_rolls.max(1) as f64;
+        let exact = self.get_joint_probabilities();
+        let frequencies = counts.mapv(|count| count as f64 / n);
+        let errors = (&frequencies - &exact).mapv(f64::abs);
+        let max_cell_error = errors.diag().iter().copied().fold(0., f64::max);
+        let max_joint_error = errors.iter().copied().fold(0., f64::max);
+        let cell_frequencies = Array2::from_shape_fn((GRID_HEIGHT, GRID_WIDTH), |cell| {
+            let index = to_index(cell);
+            frequencies[[index, index]]
+        });
+        return MonteCarloReport {num_rolls, cell_frequencies, max_cell_error, max_joint_error};
+    }
+
+    /// Table of the probabilities of the cells being blocked (in percent)
+    pub fn pretty_cells(&self) -> String {
+        pretty_table(&self.get_cell_probabilities())
+    }
+}
+
+impl Default for DiceAnalysis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Table of probabilities of the cells (in percent)
+pub fn pretty_table(probabilities: &Array2<f64>) -> String {
+    let head: String = (0..probabilities.ncols())
+        .map(|j| format!("{:>7}", to_label((0, j)).chars().next().unwrap()))
+        .collect();
+    let mut lines = vec![format!("   {head}")];
+    for (i, row) in probabilities.rows().into_iter().enumerate() {
+        let line: String = row.iter().map(|p| format!("{:>6.1}%", p * 100.)).collect();
+        lines.push(format!("{:>2} {line}", i + 1));
+    }
+    return lines.join("\n");
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+fn to_index(cell: (usize, usize)) -> usize {
+    let (i, j) = cell;
+    return i * GRID_WIDTH + j;
+}
+
+fn to_cell(index: usize) -> (usize, usize) {
+    return (index / GRID_WIDTH, index % GRID_WIDTH);
+}
+
+fn to_label(cell: (usize, usize)) -> String {
+    let (i, j) = cell;
+    return Die::from_coords(i, j).to_string();
+}
@@ -0,0 +1,46 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use itertools::Itertools;
+use rand_chacha::ChaCha8Rng;
+use rand::prelude::IndexedRandom;
+
+use crate::games::genius_square::models::constants::dice::*;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::pieces::models::Piece;
+use super::models::Die;
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+pub fn roll_dice(
+    rng: &mut ChaCha8Rng,
+) -> Vec<String> {
+    DICE
+    .iter()
+    .map(|die| die.choose(rng).unwrap().to_string())
+    .collect()
+}
+
+/// Converts the faces of a roll of the dice to the blocking piece.
+pub fn block_from_roll(
+    faces: &[String],
+) -> Piece {
+    let coords = faces
+        .iter()
+        .map(|face| Die::from_string(face).to_coords())
+        .collect();
+    Piece::from_coords(coords, Some(EnumPiece::Block))
+}
+
+/// Lists every distinct roll of the dice
+/// (faces which occur more than once on a die are only used once).
+pub fn all_rolls() -> Vec<Vec<String>> {
+    DICE
+        .iter()
+        .map(|die| die.iter().unique().map(|face| face.to_string()).collect::<Vec<String>>())
+        .multi_cartesian_product()
+        .collect()
+}
@@ -1,4 +1,8 @@
 /// Models for handling dice
 
+pub mod analysis;
 pub mod methods;
 pub mod models;
+
+#[cfg(test)]
+pub mod tests_analysis;
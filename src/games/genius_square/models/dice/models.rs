@@ -7,7 +7,7 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result;
 
-use crate::models::constants::dice::*;
+use crate::games::genius_square::models::constants::dice::*;
 
 /// ----------------------------------------------------------------
 /// STRUCTS
@@ -19,6 +19,15 @@ pub struct Die {
     j: usize,
 }
 
+/// Ways in which the dice can produce a layout of blocked cells.
+#[derive(Clone, Debug)]
+pub struct LayoutMatch {
+    /// faces of the dice (one per die, in order) for every possible roll
+    pub assignments: Vec<Vec<String>>,
+    /// probability of the layout under fair dice
+    pub probability: f64,
+}
+
 /// ----------------------------------------------------------------
 /// IMPLEMENTATIONS
 /// ----------------------------------------------------------------
@@ -53,6 +62,13 @@ impl Die {
     }
 }
 
+impl LayoutMatch {
+    /// Whether the dice can produce the layout
+    pub fn is_legal(&self) -> bool {
+        !self.assignments.is_empty()
+    }
+}
+
 impl Display for Die {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "{}", self.to_string())
@@ -0,0 +1,78 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use rstest::rstest;
+
+use super::analysis::*;
+use crate::_core::rand::seed_rng;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest]
+    #[case((0, 5), 3. / 6.)] // F1 is on three faces of die 2
+    #[case((1, 5), 2. / 6.)] // F2 is on two faces of die 1
+    #[case((0, 0), 1. / 6.)]
+    #[case((0, 1), 1. / 6.)]
+    fn test_cell_probability(#[case] cell: (usize, usize), #[case] expected: f64) {
+        let analysis = DiceAnalysis::new();
+        assert!((analysis.get_cell_probability(cell) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_expected_number_of_blocks() {
+        // no two dice share a cell, so that exactly seven cells are blocked
+        let analysis = DiceAnalysis::new();
+        let total: f64 = analysis.get_cell_probabilities().sum();
+        assert!((total - 7.).abs() < 1e-12);
+        assert!(analysis.get_never_blocked().is_empty());
+    }
+
+    #[test]
+    fn test_joint_probability() {
+        let analysis = DiceAnalysis::new();
+        // A6 and F1 are on the same die
+        assert_eq!(analysis.get_joint_probability((5, 0), (0, 5)), 0.);
+        assert!(analysis.get_covariance((5, 0), (0, 5)) < 0.);
+        // A1 and B1 are on different dice
+        assert!((analysis.get_joint_probability((0, 0), (0, 1)) - 1. / 36.).abs() < 1e-12);
+        assert!(analysis.get_covariance((0, 0), (0, 1)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_skews() {
+        let analysis = DiceAnalysis::new();
+        let skews = analysis.get_skews();
+        let faces: Vec<(usize, &str)> = skews.iter().map(|skew| (skew.die, skew.face.as_str())).collect();
+        assert_eq!(faces, vec![(0, "A5"), (0, "F2"), (1, "A6"), (1, "F1")]);
+        assert!((skews[0].probability - 1. / 3.).abs() < 1e-12);
+        assert!((skews[0].probability_unskewed - 1. / 4.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_modified_die() {
+        let faces: Vec<String> = ["A6"; 6].iter().map(|x| x.to_string()).collect();
+        let analysis = DiceAnalysis::new().with_die(1, faces).unwrap();
+        assert_eq!(analysis.get_never_blocked(), vec!["F1".to_string()]);
+        assert_eq!(analysis.get_cell_probability((5, 0)), 1.);
+        assert!(DiceAnalysis::new().with_die(7, vec![]).is_err());
+    }
+
+    #[test]
+    fn test_monte_carlo() {
+        let analysis = DiceAnalysis::new();
+        let mut rng = seed_rng(Some("dice".to_string()));
+        let report = analysis.monte_carlo(20000, &mut rng);
+        assert_eq!(report.num_rolls, 20000);
+        // the standard error is at most 0.5 / sqrt(20000) < 0.004
+        assert!(report.max_cell_error < 0.02);
+        assert!(report.max_joint_error < 0.02);
+    }
+}
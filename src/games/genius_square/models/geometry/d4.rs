@@ -0,0 +1,168 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result;
+use std::ops::Mul;
+use std::str::FromStr;
+
+use super::models::Cell;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Element of the dihedral group of the square (the symmetries of the square grid),
+/// i.e. `turns` quarter turns anticlockwise, followed by an h-flip if `flip`.
+///
+/// Elements are written as `r<turns>` or `r<turns>:h` (e.g. `r1:h`),
+/// and `a * b` applies `b` first.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct D4 {
+    turns: u8,
+    flip: bool,
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl D4 {
+    pub fn identity() -> Self {
+        Self::default()
+    }
+
+    /// Rotation by `k` quarter turns anticlockwise (`k` may be negative).
+    pub fn rotation(k: isize) -> Self {
+        Self {turns: k.rem_euclid(4) as u8, flip: false}
+    }
+
+    /// Reflection in the vertical axis (`j -> -j`).
+    pub fn hflip() -> Self {
+        Self {turns: 0, flip: true}
+    }
+
+    /// Reflection in the horizontal axis (`i -> -i`).
+    pub fn vflip() -> Self {
+        Self::hflip() * Self::rotation(2)
+    }
+
+    /// Reflection in the main diagonal (`(i, j) -> (j, i)`).
+    pub fn transpose() -> Self {
+        Self::hflip() * Self::rotation(-1)
+    }
+
+    /// All elements, ordered as the symmetries of the square grid
+    /// (see [`D4::from_index`]).
+    pub fn all() -> Vec<Self> {
+        (0..8).map(Self::from_index).collect()
+    }
+
+    /// The `k`-th symmetry of the square grid (see [`GridGeometry::transform`]),
+    /// i.e. a quarter turn (if `k >= 4`),
+    /// followed by a v-flip (if `k & 2`) and an h-flip (if `k & 1`).
+    ///
+    /// [`GridGeometry::transform`]: super::models::GridGeometry::transform
+    pub fn from_index(k: usize) -> Self {
+        let mut element = Self::identity();
+        if k & 4 != 0 {
+            element = Self::rotation(1) * element;
+        }
+        if k & 2 != 0 {
+            element = Self::vflip() * element;
+        }
+        if k & 1 != 0 {
+            element = Self::hflip() * element;
+        }
+        return element;
+    }
+
+    /// Inverse of [`D4::from_index`].
+    pub fn index(&self) -> usize {
+        (0..8).find(|&k| Self::from_index(k) == *self).unwrap()
+    }
+
+    pub fn get_turns(&self) -> u8 {
+        self.turns
+    }
+
+    pub fn is_flip(&self) -> bool {
+        self.flip
+    }
+
+    /// Applies `other` first and then `self`.
+    pub fn compose(&self, other: &Self) -> Self {
+        // uses that a flip reverses the sense of rotation: r h = h r^-1
+        let turns = if other.flip { other.turns as isize - self.turns as isize } else { (other.turns + self.turns) as isize };
+        return Self {turns: turns.rem_euclid(4) as u8, flip: self.flip != other.flip};
+    }
+
+    pub fn inverse(&self) -> Self {
+        if self.flip {
+            // every reflection is its own inverse
+            return *self;
+        }
+        return Self::rotation(-(self.turns as isize));
+    }
+
+    /// Smallest `n >= 1` with `g^n` the identity.
+    pub fn order(&self) -> usize {
+        match (self.flip, self.turns) {
+            (true, _) => 2,
+            (false, 0) => 1,
+            (false, 2) => 2,
+            (false, _) => 4,
+        }
+    }
+
+    /// Applies the element to a cell (about the origin).
+    pub fn apply(&self, cell: Cell) -> Cell {
+        let (mut i, mut j) = cell;
+        for _ in 0..self.turns {
+            (i, j) = (-j, i);
+        }
+        if self.flip {
+            j = -j;
+        }
+        return (i, j);
+    }
+}
+
+impl Display for D4 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.flip {
+            return write!(f, "r{}:h", self.turns);
+        }
+        write!(f, "r{}", self.turns)
+    }
+}
+
+impl FromStr for D4 {
+    type Err = String;
+
+    fn from_str(text: &str) -> std::result::Result<Self, Self::Err> {
+        let text = text.trim();
+        let (rotation, flip) = match text.split_once(':') {
+            Some((rotation, "h")) => (rotation, true),
+            Some(_) => return Err(format!("Invalid orientation {text} (expected e.g. r1 or r1:h).")),
+            None => (text, false),
+        };
+        let turns: isize = rotation
+            .strip_prefix('r')
+            .and_then(|k| k.parse().ok())
+            .ok_or_else(|| format!("Invalid orientation {text} (expected e.g. r1 or r1:h)."))?;
+        let element = Self::rotation(turns);
+        return Ok(if flip { Self::hflip() * element } else { element });
+    }
+}
+
+impl Mul for D4 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.compose(&other)
+    }
+}
@@ -0,0 +1,9 @@
+/// Models for the geometry of grids (square, hexagonal, triangular)
+
+pub mod d4;
+pub mod models;
+
+#[cfg(test)]
+pub mod tests_models;
+#[cfg(test)]
+pub mod tests_d4;
@@ -0,0 +1,243 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::fmt::Debug;
+
+/// ----------------------------------------------------------------
+/// STRUCTS AND CONSTANTS
+/// ----------------------------------------------------------------
+
+/// Position `(i, j)` of a cell in the array storing a grid,
+/// which may lie outside the array during transformations.
+pub type Cell = (isize, isize);
+
+/// Geometry of a grid of cells stored in a rectangular array.
+///
+/// A geometry determines
+///
+/// - its point group of rotations and reflections (see [`GridGeometry::transform`]),
+/// - which translations map the grid onto itself (see [`GridGeometry::is_translation`]),
+/// - the neighbourhood of a cell (cells sharing an edge),
+/// - the labels of cells (e.g. `B2`).
+pub trait GridGeometry: Debug + Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Order of the point group (rotations and reflections).
+    fn num_symmetries(&self) -> usize;
+
+    /// Applies the `k`-th element of the point group to a cell
+    /// (up to translation), where `k = 0` is the identity.
+    fn transform(&self, cell: Cell, k: usize) -> Cell;
+
+    /// Cells sharing an edge with the cell.
+    fn neighbours(&self, cell: Cell) -> Vec<Cell>;
+
+    /// Whether shifting by `(di, dj)` maps the grid onto itself.
+    fn is_translation(&self, _di: isize, _dj: isize) -> bool {
+        true
+    }
+
+    /// Label of a cell: column letter followed by row number (e.g. `B2`).
+    fn label(&self, cell: (usize, usize)) -> String {
+        let (i, j) = cell;
+        let column = (b'A' + j as u8) as char;
+        return format!("{column}{}", i + 1);
+    }
+
+    /// Recovers a cell from its label (see [`GridGeometry::label`]).
+    fn parse_label(&self, text: &str) -> Option<(usize, usize)> {
+        let mut chars = text.trim().chars();
+        let column = chars.next()?.to_ascii_uppercase();
+        if !column.is_ascii_uppercase() {
+            return None;
+        }
+        let row: usize = chars.as_str().parse().ok()?;
+        if row == 0 {
+            return None;
+        }
+        return Some((row - 1, (column as u8 - b'A') as usize));
+    }
+
+    /// Translates cells as far as possible to the top left
+    /// (using only translations which map the grid onto itself).
+    fn normalise(&self, cells: &[Cell]) -> Vec<Cell> {
+        let i_min = cells.iter().map(|&(i, _)| i).min().unwrap_or(0);
+        let mut j_min = cells.iter().map(|&(_, j)| j).min().unwrap_or(0);
+        while !self.is_translation(0, j_min) {
+            j_min -= 1;
+        }
+        let mut result: Vec<Cell> = cells.iter().map(|&(i, j)| (i - i_min, j - j_min)).collect();
+        result.sort();
+        return result;
+    }
+}
+
+/// Square grid with the 8 symmetries of the square and 4 neighbours per cell.
+///
+/// Cells are labelled by column letter and row number (e.g. `B2`).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SquareGrid;
+
+/// Hexagonal grid in axial co-ordinates
+/// (row `i`, diagonal column `j`; the array is a rhombus),
+/// with the 12 symmetries of the hexagon and 6 neighbours per cell.
+///
+/// Cells are labelled by column letter and row number (e.g. `B2`).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HexGrid;
+
+/// Triangular grid, where every row alternates between upward (even `j`)
+/// and downward (odd `j`) triangles, with the 12 symmetries of the hexagon
+/// and 3 neighbours per cell.
+///
+/// Cells are labelled by the letter of their pair of triangles,
+/// the row number and the orientation (e.g. `B2^` or `B2v`).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TriGrid;
+
+pub static SQUARE_GRID: SquareGrid = SquareGrid;
+pub static HEX_GRID: HexGrid = HexGrid;
+pub static TRI_GRID: TriGrid = TriGrid;
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl GridGeometry for SquareGrid {
+    fn name(&self) -> &'static str {
+        "square"
+    }
+
+    fn num_symmetries(&self) -> usize {
+        8
+    }
+
+    /// The elements are a quarter turn (if `k >= 4`),
+    /// followed by a v-flip (if `k & 2`) and an h-flip (if `k & 1`).
+    fn transform(&self, cell: Cell, k: usize) -> Cell {
+        let (mut i, mut j) = cell;
+        if k >= 4 {
+            (i, j) = (-j, i);
+        }
+        if k & 2 != 0 {
+            i = -i;
+        }
+        if k & 1 != 0 {
+            j = -j;
+        }
+        return (i, j);
+    }
+
+    fn neighbours(&self, cell: Cell) -> Vec<Cell> {
+        let (i, j) = cell;
+        vec![(i - 1, j), (i + 1, j), (i, j - 1), (i, j + 1)]
+    }
+}
+
+impl GridGeometry for HexGrid {
+    fn name(&self) -> &'static str {
+        "hexagonal"
+    }
+
+    fn num_symmetries(&self) -> usize {
+        12
+    }
+
+    /// The elements are a reflection (if `k >= 6`)
+    /// followed by `k mod 6` turns by 60 degrees.
+    fn transform(&self, cell: Cell, k: usize) -> Cell {
+        let (mut i, mut j) = cell;
+        if k >= 6 {
+            (i, j) = (j, i);
+        }
+        for _ in 0..(k % 6) {
+            (i, j) = (i + j, -i);
+        }
+        return (i, j);
+    }
+
+    fn neighbours(&self, cell: Cell) -> Vec<Cell> {
+        let (i, j) = cell;
+        vec![(i, j - 1), (i, j + 1), (i - 1, j), (i + 1, j), (i - 1, j + 1), (i + 1, j - 1)]
+    }
+}
+
+impl TriGrid {
+    /// Corners of a triangle in lattice co-ordinates
+    fn to_vertices(cell: Cell) -> [Cell; 3] {
+        let (b, j) = cell;
+        let a = j.div_euclid(2);
+        if j.rem_euclid(2) == 0 {
+            return [(a, b), (a + 1, b), (a, b + 1)];
+        }
+        return [(a + 1, b), (a, b + 1), (a + 1, b + 1)];
+    }
+
+    /// Triangle with the given corners in lattice co-ordinates
+    fn from_vertices(vertices: &[Cell; 3]) -> Cell {
+        let a = vertices.iter().map(|&(x, _)| x).min().unwrap();
+        let b = vertices.iter().map(|&(_, y)| y).min().unwrap();
+        let total: isize = vertices.iter().map(|&(x, y)| x + y).sum();
+        let up = total == 3 * (a + b) + 2;
+        return (b, 2 * a + if up { 0 } else { 1 });
+    }
+}
+
+impl GridGeometry for TriGrid {
+    fn name(&self) -> &'static str {
+        "triangular"
+    }
+
+    fn num_symmetries(&self) -> usize {
+        12
+    }
+
+    /// The elements are a reflection (if `k >= 6`)
+    /// followed by `k mod 6` turns by 60 degrees about a vertex.
+    fn transform(&self, cell: Cell, k: usize) -> Cell {
+        let mut vertices = Self::to_vertices(cell);
+        for vertex in vertices.iter_mut() {
+            let (mut x, mut y) = *vertex;
+            if k >= 6 {
+                (x, y) = (y, x);
+            }
+            for _ in 0..(k % 6) {
+                (x, y) = (-y, x + y);
+            }
+            *vertex = (x, y);
+        }
+        return Self::from_vertices(&vertices);
+    }
+
+    fn neighbours(&self, cell: Cell) -> Vec<Cell> {
+        let (i, j) = cell;
+        if j.rem_euclid(2) == 0 {
+            return vec![(i, j - 1), (i, j + 1), (i - 1, j + 1)];
+        }
+        return vec![(i, j - 1), (i, j + 1), (i + 1, j - 1)];
+    }
+
+    /// Only shifts by an even number of columns preserve the orientation of the triangles.
+    fn is_translation(&self, _di: isize, dj: isize) -> bool {
+        dj.rem_euclid(2) == 0
+    }
+
+    fn label(&self, cell: (usize, usize)) -> String {
+        let (i, j) = cell;
+        let column = (b'A' + (j / 2) as u8) as char;
+        let orientation = if j % 2 == 0 { '^' } else { 'v' };
+        return format!("{column}{}{orientation}", i + 1);
+    }
+
+    fn parse_label(&self, text: &str) -> Option<(usize, usize)> {
+        let text = text.trim();
+        let offset = match text.chars().last()? {
+            '^' => 0,
+            'v' | 'V' => 1,
+            _ => return None,
+        };
+        let (i, j) = SQUARE_GRID.parse_label(&text[..text.len() - 1])?;
+        return Some((i, 2 * j + offset));
+    }
+}
@@ -0,0 +1,71 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use itertools::iproduct;
+use rstest::rstest;
+
+use super::d4::*;
+use super::models::*;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agrees_with_square_grid() {
+        let cells = [(0, 0), (1, 2), (-3, 1)];
+        for (k, element) in D4::all().iter().enumerate() {
+            assert_eq!(element.index(), k);
+            for &cell in cells.iter() {
+                assert_eq!(element.apply(cell), SQUARE_GRID.transform(cell, k), "symmetry {k}");
+            }
+        }
+        assert_eq!(D4::all().iter().collect::<std::collections::HashSet<_>>().len(), 8);
+    }
+
+    #[test]
+    fn test_group_laws() {
+        let elements = D4::all();
+        let cell = (1, 2);
+        for (a, b) in iproduct!(elements.iter(), elements.iter()) {
+            // composition applies the right factor first
+            assert_eq!((*a * *b).apply(cell), a.apply(b.apply(cell)));
+            for c in elements.iter() {
+                assert_eq!((*a * *b) * *c, *a * (*b * *c));
+            }
+        }
+        for a in elements.iter() {
+            assert_eq!(*a * a.inverse(), D4::identity());
+            assert_eq!(a.inverse() * *a, D4::identity());
+            let power = (0..a.order()).fold(D4::identity(), |g, _| g * *a);
+            assert_eq!(power, D4::identity());
+        }
+    }
+
+    #[rstest]
+    #[case(D4::identity(), "r0", 1)]
+    #[case(D4::rotation(1), "r1", 4)]
+    #[case(D4::rotation(-2), "r2", 2)]
+    #[case(D4::hflip(), "r0:h", 2)]
+    #[case(D4::hflip() * D4::rotation(1), "r1:h", 2)]
+    fn test_notation(#[case] element: D4, #[case] text: &str, #[case] order: usize) {
+        assert_eq!(element.to_string(), text);
+        assert_eq!(text.parse::<D4>(), Ok(element));
+        assert_eq!(element.order(), order);
+    }
+
+    #[test]
+    fn test_reflections() {
+        assert_eq!(D4::vflip().apply((1, 2)), (-1, 2));
+        assert_eq!(D4::transpose().apply((1, 2)), (2, 1));
+        assert_eq!("r5".parse::<D4>(), Ok(D4::rotation(1)));
+        assert!("r1:v".parse::<D4>().is_err());
+        assert!("h".parse::<D4>().is_err());
+    }
+}
@@ -0,0 +1,124 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use rstest::rstest;
+
+use super::models::*;
+use crate::games::genius_square::algorithms::solve::solve_pieces_with_options;
+use crate::games::genius_square::models::arrays::models::BinArray;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::pieces::models::Piece;
+use crate::games::genius_square::models::solver::models::SolveOptions;
+use crate::games::genius_square::models::solver::models::SolveStatus;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest]
+    // L-tetromino
+    #[case(&SQUARE_GRID, vec![(0, 0), (1, 0), (2, 0), (2, 1)], 8)]
+    // square tetromino
+    #[case(&SQUARE_GRID, vec![(0, 0), (0, 1), (1, 0), (1, 1)], 1)]
+    // pair of hexagons
+    #[case(&HEX_GRID, vec![(0, 0), (0, 1)], 3)]
+    // bent line of three hexagons
+    #[case(&HEX_GRID, vec![(0, 0), (0, 1), (1, 1)], 6)]
+    // single triangle
+    #[case(&TRI_GRID, vec![(0, 0)], 2)]
+    // pair of triangles
+    #[case(&TRI_GRID, vec![(0, 0), (0, 1)], 3)]
+    fn test_orientations(
+        #[case] geometry: &'static dyn GridGeometry,
+        #[case] coords: Vec<(usize, usize)>,
+        #[case] expected: usize,
+    ) {
+        let arr = BinArray::from_coords_on(geometry, coords, 4, 6);
+        assert_eq!(arr.get_orientations().len(), expected);
+    }
+
+    #[rstest]
+    #[case(&SQUARE_GRID, (0, 0), 4)]
+    #[case(&HEX_GRID, (0, 0), 6)]
+    #[case(&TRI_GRID, (0, 0), 3)]
+    #[case(&TRI_GRID, (0, 1), 3)]
+    fn test_symmetries_preserve_neighbours(
+        #[case] geometry: &'static dyn GridGeometry,
+        #[case] cell: Cell,
+        #[case] num_neighbours: usize,
+    ) {
+        let neighbours = geometry.neighbours(cell);
+        assert_eq!(neighbours.len(), num_neighbours);
+        for k in 0..geometry.num_symmetries() {
+            let image = geometry.transform(cell, k);
+            let mut expected: Vec<Cell> = neighbours.iter().map(|&x| geometry.transform(x, k)).collect();
+            let mut actual = geometry.neighbours(image);
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected, "symmetry {k} of the {} grid", geometry.name());
+        }
+    }
+
+    #[rstest]
+    #[case(&SQUARE_GRID, (1, 3), "D2")]
+    #[case(&HEX_GRID, (0, 0), "A1")]
+    #[case(&TRI_GRID, (1, 2), "B2^")]
+    #[case(&TRI_GRID, (1, 3), "B2v")]
+    fn test_labels(
+        #[case] geometry: &'static dyn GridGeometry,
+        #[case] cell: (usize, usize),
+        #[case] label: &str,
+    ) {
+        assert_eq!(geometry.label(cell), label);
+        assert_eq!(geometry.parse_label(label), Some(cell));
+    }
+
+    #[test]
+    fn test_solve_hex() {
+        // a pair of hexagons, which has to be turned to fit
+        let pieces = vec![
+            Piece::from_coords_on(&HEX_GRID, vec![(0, 0), (1, 0)], 2, 2, Some(EnumPiece::Symb4)),
+        ];
+
+        // the hexagons across the short diagonal of the rhombus are neighbours
+        let block = Piece::from_coords_on(&HEX_GRID, vec![(0, 0), (1, 1)], 2, 2, Some(EnumPiece::Block));
+        let outcome = solve_pieces_with_options(&GameBoard::new(&block), &pieces, &SolveOptions::new());
+        assert_eq!(outcome.status, SolveStatus::Solved);
+        assert_eq!(outcome.board.unwrap().to_rows(), vec!["■4", "4■"]);
+
+        // but not those across the long diagonal
+        let block = Piece::from_coords_on(&HEX_GRID, vec![(0, 1), (1, 0)], 2, 2, Some(EnumPiece::Block));
+        let outcome = solve_pieces_with_options(&GameBoard::new(&block), &pieces, &SolveOptions::new());
+        assert_eq!(outcome.status, SolveStatus::Exhausted);
+    }
+
+    #[test]
+    fn test_solve_tri() {
+        // 2 rows of 2 triangles with one triangle blocked,
+        // covered by 3 triangles, which have to be turned to fit
+        let block = Piece::from_coords_on(&TRI_GRID, vec![(1, 1)], 2, 2, Some(EnumPiece::Block));
+        let pieces = vec![
+            Piece::from_coords_on(&TRI_GRID, vec![(0, 1), (1, 0), (1, 1)], 2, 2, Some(EnumPiece::T)),
+        ];
+        let outcome = solve_pieces_with_options(&GameBoard::new(&block), &pieces, &SolveOptions::new());
+        assert_eq!(outcome.status, SolveStatus::Solved);
+        assert_eq!(outcome.board.unwrap().to_rows(), vec!["TT", "T■"]);
+
+        // a pair cannot cover a triangle whose only neighbour is blocked
+        let block = Piece::from_coords_on(&TRI_GRID, vec![(0, 1)], 1, 4, Some(EnumPiece::Block));
+        let pieces = vec![
+            Piece::from_coords_on(&TRI_GRID, vec![(0, 0), (0, 1)], 1, 4, Some(EnumPiece::Symb4)),
+            Piece::from_coords_on(&TRI_GRID, vec![(0, 0)], 1, 4, Some(EnumPiece::L)),
+        ];
+        let outcome = solve_pieces_with_options(&GameBoard::new(&block), &pieces, &SolveOptions::new());
+        assert_eq!(outcome.status, SolveStatus::Solved);
+        assert_eq!(outcome.board.unwrap().to_rows(), vec!["L■44"]);
+    }
+}
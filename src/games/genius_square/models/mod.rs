@@ -0,0 +1,15 @@
+/// Models used in game.
+
+pub mod arrays;
+pub mod bench;
+pub mod board;
+pub mod cnf;
+pub mod constants;
+pub mod coverage;
+pub mod deduction;
+pub mod dice;
+pub mod geometry;
+pub mod pieces;
+pub mod race;
+pub mod record;
+pub mod solver;
@@ -0,0 +1,128 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::sync::LazyLock;
+
+use crate::games::genius_square::models::arrays::models::BinArray;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::geometry::d4::D4;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// A fixed orientation of a piece (shifted to the top left).
+#[derive(Clone, Debug)]
+pub struct Orientation {
+    /// canonical index of the orientation, i.e. its position in [`PieceInfo::orientations`]
+    pub id: usize,
+    /// first symmetry of the square grid (see [`D4::from_index`]) leading to the orientation
+    pub element: D4,
+    pub positions: BinArray,
+}
+
+/// Shape and display data of a piece.
+#[derive(Clone, Debug)]
+pub struct PieceInfo {
+    pub kind: EnumPiece,
+    pub name: &'static str,
+    pub colour: &'static str,
+    /// colour in the 256-colour palette of terminals
+    pub colour_code: u8,
+    pub num_cells: usize,
+    /// number of symmetries of the square grid which map the shape onto itself
+    pub symmetry_order: usize,
+    /// distinct fixed orientations, in the order in which the solver tries them
+    pub orientations: Vec<Orientation>,
+}
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+/// Catalogue of the pieces in the order of [`ENUM_PIECES`].
+///
+/// [`ENUM_PIECES`]: crate::games::genius_square::models::constants::enums::ENUM_PIECES
+pub static CATALOGUE: LazyLock<Vec<PieceInfo>> = LazyLock::new(|| {
+    EnumPiece::iter().map(|kind| PieceInfo::new(&kind)).collect()
+});
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl PieceInfo {
+    pub fn new(kind: &EnumPiece) -> Self {
+        let shape = kind.get_positions();
+        let orientations: Vec<Orientation> = shape
+            .get_distinct_symmetries()
+            .into_iter()
+            .enumerate()
+            .map(|(id, k)| Orientation {
+                id,
+                element: D4::from_index(k),
+                positions: shape.transform_symmetry(k),
+            })
+            .collect();
+        let (colour, colour_code) = get_colour(kind);
+        return Self {
+            kind: kind.clone(),
+            name: kind.name(),
+            colour,
+            colour_code,
+            num_cells: shape.get_weight() as usize,
+            symmetry_order: D4::all().len() / orientations.len(),
+            orientations,
+        };
+    }
+
+    /// Entry of the catalogue (only for the pieces in `ENUM_PIECES`)
+    pub fn of(kind: &EnumPiece) -> Option<&'static PieceInfo> {
+        CATALOGUE.iter().find(|info| info.kind == *kind)
+    }
+
+    pub fn get_orientation(&self, id: usize) -> Option<&Orientation> {
+        self.orientations.get(id)
+    }
+
+    /// Determines the orientation of positions of the piece anywhere on the board.
+    pub fn find_orientation(&self, positions: &BinArray) -> Option<&Orientation> {
+        let coords = positions.recentre().to_coords();
+        return self
+            .orientations
+            .iter()
+            .find(|orientation| orientation.positions.to_coords() == coords);
+    }
+
+    /// Determines the orientation obtained by applying a symmetry to the shape.
+    pub fn find_element(&self, element: &D4) -> Option<&Orientation> {
+        self.find_orientation(&self.kind.get_positions().transform_d4(element))
+    }
+
+    /// Renders the text in the colour of the piece (for terminals).
+    pub fn paint(&self, text: &str) -> String {
+        format!("\x1b[38;5;{}m{text}\x1b[0m", self.colour_code)
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY METHODS
+/// ----------------------------------------------------------------
+
+/// Colours of the pieces of the physical game
+fn get_colour(kind: &EnumPiece) -> (&'static str, u8) {
+    match kind {
+        EnumPiece::Symb1 => ("blue", 33),
+        EnumPiece::Symb2 => ("brown", 130),
+        EnumPiece::Symb3 => ("orange", 208),
+        EnumPiece::Symb4 => ("grey", 245),
+        EnumPiece::C => ("purple", 129),
+        EnumPiece::L => ("cyan", 45),
+        EnumPiece::T => ("yellow", 220),
+        EnumPiece::X => ("green", 40),
+        EnumPiece::Z => ("red", 196),
+        EnumPiece::Block => ("white", 15),
+        EnumPiece::Blank => ("black", 0),
+    }
+}
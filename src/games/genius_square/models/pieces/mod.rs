@@ -1,4 +1,8 @@
 /// Models for handling pieces
 
+pub mod catalogue;
 pub mod methods;
 pub mod models;
+
+#[cfg(test)]
+pub mod tests_catalogue;
@@ -9,9 +9,11 @@ use std::fmt::Result;
 use std::ops::Add;
 use std::ops::Mul;
 
-use crate::models::arrays::models::BinArray;
-use crate::models::constants::board::*;
-use crate::models::constants::enums::*;
+use crate::games::genius_square::models::arrays::models::BinArray;
+use crate::games::genius_square::models::constants::board::*;
+use crate::games::genius_square::models::constants::enums::*;
+use crate::games::genius_square::models::geometry::models::GridGeometry;
+use crate::games::genius_square::models::pieces::catalogue::PieceInfo;
 
 /// ----------------------------------------------------------------
 /// STRUCTS
@@ -45,6 +47,20 @@ impl Piece {
         Self {kind, positions}
     }
 
+    /// Creates a piece on an `m x n` array of a grid of the given geometry.
+    #[allow(unused)]
+    pub fn from_coords_on(
+        geometry: &'static dyn GridGeometry,
+        coords: Vec<(usize, usize)>,
+        m: usize,
+        n: usize,
+        option_kind: Option<EnumPiece>,
+    ) -> Self {
+        let positions = BinArray::from_coords_on(geometry, coords, m, n);
+        let kind = option_kind.unwrap_or(EnumPiece::Blank);
+        Self {kind, positions}
+    }
+
     pub fn to_coords(&self) -> Vec<(usize, usize)> {
         self.positions.to_coords()
     }
@@ -61,6 +77,13 @@ impl Piece {
         self.kind.to_formatted().to_string()
     }
 
+    /// Canonical index of the orientation of the piece (see [`PieceInfo::find_orientation`]),
+    /// if the positions are a copy of the shape of its kind.
+    pub fn get_orientation_id(&self) -> Option<usize> {
+        let info = PieceInfo::of(&self.kind)?;
+        return info.find_orientation(&self.positions).map(|orientation| orientation.id);
+    }
+
     pub fn get_positions(&self) -> &BinArray {
         &self.positions
     }
@@ -81,7 +104,7 @@ impl Piece {
     }
 
     pub fn to_string(&self) -> String {
-        let n = GRID_WIDTH;
+        let (_, n) = self.positions.get_shape();
         let hbar = "\u{2500}".repeat(n + 2);
         let top = format!("\u{250C}{hbar}\u{2510}");
         let bot = format!("\u{2514}{hbar}\u{2518}");
@@ -0,0 +1,71 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use rstest::rstest;
+
+use super::catalogue::*;
+use super::models::Piece;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::geometry::d4::D4;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest]
+    #[case(EnumPiece::Symb1, 1, 1)]
+    #[case(EnumPiece::Symb2, 2, 2)]
+    #[case(EnumPiece::Symb3, 3, 2)]
+    #[case(EnumPiece::Symb4, 4, 2)]
+    #[case(EnumPiece::C, 3, 4)]
+    #[case(EnumPiece::L, 4, 8)]
+    #[case(EnumPiece::T, 4, 4)]
+    #[case(EnumPiece::X, 4, 1)]
+    #[case(EnumPiece::Z, 4, 4)]
+    fn test_catalogue(
+        #[case] kind: EnumPiece,
+        #[case] num_cells: usize,
+        #[case] num_orientations: usize,
+    ) {
+        let info = PieceInfo::of(&kind).unwrap();
+        assert_eq!(info.num_cells, num_cells);
+        assert_eq!(info.orientations.len(), num_orientations);
+        assert_eq!(info.symmetry_order * num_orientations, 8);
+        // every symmetry leads to one of the orientations
+        for element in D4::all() {
+            let orientation = info.find_element(&element).unwrap();
+            assert_eq!(info.get_orientation(orientation.id).unwrap().element, orientation.element);
+        }
+        for orientation in info.orientations.iter() {
+            assert_eq!(info.find_element(&orientation.element).unwrap().id, orientation.id);
+        }
+    }
+
+    #[test]
+    fn test_orientation_of_placed_piece() {
+        // the L-tetromino standing upright in the bottom right corner
+        let piece = Piece::from_coords(vec![(3, 5), (4, 5), (5, 4), (5, 5)], Some(EnumPiece::L));
+        let id = piece.get_orientation_id().unwrap();
+        let info = PieceInfo::of(&EnumPiece::L).unwrap();
+        assert_eq!(info.find_orientation(piece.get_positions()).unwrap().id, id);
+        assert_eq!(Piece::from_coords(vec![(0, 0), (1, 1)], Some(EnumPiece::Symb2)).get_orientation_id(), None);
+        assert!(PieceInfo::of(&EnumPiece::Block).is_none());
+    }
+
+    #[rstest]
+    #[case("L", EnumPiece::L)]
+    #[case("z", EnumPiece::Z)]
+    #[case("3", EnumPiece::Symb3)]
+    #[case("t-tetromino", EnumPiece::T)]
+    #[case(" monomino ", EnumPiece::Symb1)]
+    fn test_from_str(#[case] text: &str, #[case] expected: EnumPiece) {
+        assert_eq!(text.parse::<EnumPiece>(), Ok(expected));
+        assert_eq!(EnumPiece::iter().count(), CATALOGUE.len());
+    }
+}
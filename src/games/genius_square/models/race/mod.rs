@@ -0,0 +1,6 @@
+/// Models for handling competitions between players
+
+pub mod models;
+
+#[cfg(test)]
+pub mod tests_models;
@@ -0,0 +1,146 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result;
+use std::time::Duration;
+
+use crate::_core::strings::purify_string_length;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+pub const MIN_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 4;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Accumulated results of a single player
+#[derive(Clone, Debug)]
+pub struct PlayerScore {
+    pub name: String,
+    pub wins: usize,
+    pub solved: usize,
+    pub total_time: Duration,
+    pub best_time: Option<Duration>,
+}
+
+/// Results of all players over all rounds
+#[derive(Clone, Debug)]
+pub struct Scoreboard {
+    players: Vec<PlayerScore>,
+    rounds: usize,
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl PlayerScore {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            wins: 0,
+            solved: 0,
+            total_time: Duration::ZERO,
+            best_time: None,
+        }
+    }
+
+    /// Mean time over all solved rounds
+    pub fn mean_time(&self) -> Option<Duration> {
+        if self.solved == 0 {
+            return None;
+        }
+        return Some(self.total_time / self.solved as u32);
+    }
+}
+
+impl Scoreboard {
+    pub fn new(names: &[String]) -> Self {
+        let players = names.iter().map(|name| PlayerScore::new(name)).collect();
+        Self { players, rounds: 0 }
+    }
+
+    pub fn get_rounds(&self) -> usize {
+        self.rounds
+    }
+
+    /// Records the results of a round,
+    /// viz. the time taken by each player (in the order of the players)
+    /// or `None` if the player did not solve the puzzle.
+    ///
+    /// Returns the index of the winner of the round, if anyone solved it.
+    pub fn record_round(&mut self, times: &[Option<Duration>]) -> Option<usize> {
+        self.rounds += 1;
+        for (player, time) in self.players.iter_mut().zip(times.iter()) {
+            if let Some(t) = time {
+                player.solved += 1;
+                player.total_time += *t;
+                player.best_time = Some(player.best_time.map_or(*t, |t0| t0.min(*t)));
+            }
+        }
+        let winner = times
+            .iter()
+            .enumerate()
+            .filter_map(|(k, time)| time.map(|t| (k, t)))
+            .min_by_key(|&(_, t)| t)
+            .map(|(k, _)| k);
+        if let Some(k) = winner {
+            self.players[k].wins += 1;
+        }
+        return winner;
+    }
+
+    /// Players ordered by wins (descending), then by mean time (ascending)
+    pub fn ranking(&self) -> Vec<&PlayerScore> {
+        let mut ranking: Vec<&PlayerScore> = self.players.iter().collect();
+        ranking.sort_by_key(|player| (std::cmp::Reverse(player.wins), player.mean_time().unwrap_or(Duration::MAX)));
+        return ranking;
+    }
+}
+
+impl Display for Scoreboard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let fmt_time = |t: Option<Duration>| t.map_or("-".to_string(), |t| format!("{:.1}s", t.as_secs_f64()));
+        let header = vec![
+            "#".to_string(),
+            "player".to_string(),
+            "wins".to_string(),
+            "solved".to_string(),
+            "best".to_string(),
+            "mean".to_string(),
+        ];
+        let mut rows: Vec<Vec<String>> = vec![header];
+        for (k, player) in self.ranking().iter().enumerate() {
+            rows.push(vec![
+                format!("{}", k + 1),
+                player.name.clone(),
+                format!("{}", player.wins),
+                format!("{}/{}", player.solved, self.rounds),
+                fmt_time(player.best_time),
+                fmt_time(player.mean_time()),
+            ]);
+        }
+        let widths: Vec<usize> = (0..rows[0].len())
+            .map(|j| rows.iter().map(|row| purify_string_length(&row[j])).max().unwrap_or(0))
+            .collect();
+        let lines: Vec<String> = rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(widths.iter())
+                    .map(|(cell, &w)| format!("{cell}{}", " ".repeat(w - purify_string_length(cell))))
+                    .collect::<Vec<String>>()
+                    .join(" \u{2502} ")
+            })
+            .collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
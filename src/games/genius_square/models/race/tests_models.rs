@@ -0,0 +1,82 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::time::Duration;
+
+use super::models::*;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_round() {
+        let mut scoreboard = create_scoreboard(&["ann", "bob", "cal"]);
+        assert_eq!(scoreboard.record_round(&[secs(30), secs(20), None]), Some(1));
+        assert_eq!(scoreboard.record_round(&[secs(10), None, None]), Some(0));
+        assert_eq!(scoreboard.record_round(&[None, None, None]), None);
+        assert_eq!(scoreboard.get_rounds(), 3);
+
+        let ann = find_player(&scoreboard, "ann");
+        assert_eq!((ann.wins, ann.solved), (1, 2));
+        assert_eq!(ann.best_time, secs(10));
+        assert_eq!(ann.mean_time(), secs(20));
+        let cal = find_player(&scoreboard, "cal");
+        assert_eq!((cal.wins, cal.solved), (0, 0));
+        assert_eq!((cal.best_time, cal.mean_time()), (None, None));
+    }
+
+    #[test]
+    fn test_ranking() {
+        let mut scoreboard = create_scoreboard(&["ann", "bob", "cal"]);
+        scoreboard.record_round(&[secs(30), secs(20), secs(25)]);
+        scoreboard.record_round(&[secs(30), secs(20), secs(10)]);
+        scoreboard.record_round(&[secs(5), secs(40), None]);
+        // one win each, so ordered by mean time
+        assert_eq!(get_names(&scoreboard), vec!["cal", "ann", "bob"]);
+        // more wins rank first, regardless of the mean time
+        scoreboard.record_round(&[None, secs(60), None]);
+        assert_eq!(get_names(&scoreboard), vec!["bob", "cal", "ann"]);
+    }
+
+    #[test]
+    fn test_ties() {
+        let mut scoreboard = create_scoreboard(&["ann", "bob", "cal"]);
+        // equal times: the first player in order wins
+        assert_eq!(scoreboard.record_round(&[None, secs(10), secs(10)]), Some(1));
+        assert_eq!(scoreboard.record_round(&[secs(20), secs(40), secs(20)]), Some(0));
+        // equal wins: the faster player on average ranks first
+        assert_eq!(get_names(&scoreboard), vec!["ann", "bob", "cal"]);
+        // equal wins and mean times: the order of the players is kept
+        let mut scoreboard = create_scoreboard(&["ann", "bob"]);
+        scoreboard.record_round(&[None, None]);
+        assert_eq!(get_names(&scoreboard), vec!["ann", "bob"]);
+    }
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+fn create_scoreboard(names: &[&str]) -> Scoreboard {
+    let names: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+    return Scoreboard::new(&names);
+}
+
+fn secs(t: u64) -> Option<Duration> {
+    Some(Duration::from_secs(t))
+}
+
+fn find_player<'a>(scoreboard: &'a Scoreboard, name: &str) -> &'a PlayerScore {
+    scoreboard.ranking().into_iter().find(|player| player.name == name).unwrap()
+}
+
+fn get_names(scoreboard: &Scoreboard) -> Vec<String> {
+    scoreboard.ranking().iter().map(|player| player.name.clone()).collect()
+}
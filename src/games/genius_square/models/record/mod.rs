@@ -0,0 +1,6 @@
+/// Models for the notation of placements and records of games
+
+pub mod models;
+
+#[cfg(test)]
+pub mod tests_models;
@@ -0,0 +1,299 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::games::genius_square::models::board::methods::block_from_cells;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::constants::board::GRID_HEIGHT;
+use crate::games::genius_square::models::constants::board::GRID_WIDTH;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::geometry::d4::D4;
+use crate::games::genius_square::models::geometry::models::GridGeometry;
+use crate::games::genius_square::models::geometry::models::SQUARE_GRID;
+use crate::games::genius_square::models::pieces::catalogue::PieceInfo;
+use crate::games::genius_square::models::pieces::models::Piece;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Placement of a piece in the notation `PIECE:CELL[:rK[:h]]`, e.g. `L:B2:r1:h`,
+/// consisting of
+///
+/// - the symbol of the piece,
+/// - the anchor, i.e. the first cell covered by the piece (row by row),
+/// - the symmetry (see [`D4`]) applied to the shape of the piece,
+///   which may be omitted for the identity `r0`.
+///
+/// The printer always uses the canonical symmetry of the orientation
+/// (see [`PieceInfo::orientations`]), e.g. `X:A1:r2` is printed as `X:A1`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Placement {
+    pub kind: EnumPiece,
+    pub anchor: (usize, usize),
+    pub element: D4,
+}
+
+/// A placement made at a time since the start of the game.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimedMove {
+    pub time: Duration,
+    pub placement: Placement,
+}
+
+/// Record of a game, written one entry per line:
+///
+/// ```text
+/// # comments are ignored
+/// roll B1 C4 D6 F1 F2 F3 F5
+/// seed 8c9e0a6b1f3d2e57
+/// move 4.250 L:A2:r2:h
+/// move 9.120 X:B2
+/// ...
+/// board
+/// 1■22Z■
+/// ...
+/// ```
+///
+/// The seed and the final board are optional,
+/// where empty cells of the board are written as `.`.
+/// If the board is given, it has to agree with the board after the moves.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GameRecord {
+    pub roll: Vec<String>,
+    pub seed: Option<String>,
+    pub moves: Vec<TimedMove>,
+    /// rows of the final board (see [`GameBoard::to_rows`])
+    pub board: Option<Vec<String>>,
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl Placement {
+    pub fn new(kind: &EnumPiece, anchor: (usize, usize), element: &D4) -> Self {
+        Self {kind: kind.clone(), anchor, element: *element}
+    }
+
+    /// Determines the placement of a piece on the board,
+    /// if its cells are a rotated/flipped/shifted copy of its shape.
+    pub fn from_piece(piece: &Piece) -> Option<Self> {
+        let kind = piece.get_kind();
+        let orientation = PieceInfo::of(&kind)?.find_orientation(piece.get_positions())?;
+        let anchor = *piece.to_coords().first()?;
+        return Some(Self {kind, anchor, element: orientation.element});
+    }
+
+    /// The piece covering the cells of the placement (on the board of the game).
+    pub fn to_piece(&self) -> std::result::Result<Piece, String> {
+        return self.to_piece_on(GRID_HEIGHT, GRID_WIDTH);
+    }
+
+    /// The piece covering the cells of the placement on a board of shape `m x n`.
+    pub fn to_piece_on(&self, m: usize, n: usize) -> std::result::Result<Piece, String> {
+        let info = PieceInfo::of(&self.kind)
+            .ok_or_else(|| format!("{} cannot be placed.", self.kind.name()))?;
+        let positions = &info
+            .find_element(&self.element)
+            .ok_or_else(|| format!("Invalid orientation {} of {}.", self.element, self.kind.as_str()))?
+            .positions;
+        let (i0, j0) = positions.get_anchor();
+        let (i, j) = self.anchor;
+        let coords = positions
+            .to_coords()
+            .iter()
+            .map(|&(i1, j1)| {
+                let cell = ((i1 + i).checked_sub(i0)?, (j1 + j).checked_sub(j0)?);
+                (cell.0 < m && cell.1 < n).then_some(cell)
+            })
+            .collect::<Option<Vec<(usize, usize)>>>()
+            .ok_or_else(|| format!("Placement {self} does not fit on the board."))?;
+        return Ok(Piece::from_coords_on(positions.get_geometry(), coords, m, n, Some(self.kind.clone())));
+    }
+}
+
+/// Prints the canonical form of the placement.
+impl Display for Placement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let element = PieceInfo::of(&self.kind)
+            .and_then(|info| info.find_element(&self.element))
+            .map_or(self.element, |orientation| orientation.element);
+        let cell = SQUARE_GRID.label(self.anchor);
+        if element == D4::identity() {
+            return write!(f, "{}:{cell}", self.kind.as_str());
+        }
+        write!(f, "{}:{cell}:{element}", self.kind.as_str())
+    }
+}
+
+impl FromStr for Placement {
+    type Err = String;
+
+    fn from_str(text: &str) -> std::result::Result<Self, Self::Err> {
+        let text = text.trim();
+        let parts: Vec<&str> = text.split(':').collect();
+        if parts.len() < 2 || parts.len() > 4 {
+            return Err(format!("Placement {text} must be of the form PIECE:CELL[:rK[:h]]."));
+        }
+        let kind = EnumPiece::from_symb(parts[0]).ok_or_else(|| format!("Unknown piece {}.", parts[0]))?;
+        let anchor = SQUARE_GRID
+            .parse_label(parts[1])
+            .ok_or_else(|| format!("Invalid cell {}.", parts[1].trim().to_uppercase()))?;
+        let element = match parts.len() {
+            2 => D4::identity(),
+            _ => parts[2..].join(":").parse()?,
+        };
+        return Ok(Self {kind, anchor, element});
+    }
+}
+
+impl GameRecord {
+    pub fn new(roll: &[String], seed: Option<String>) -> Self {
+        Self {roll: roll.to_vec(), seed, moves: vec![], board: None}
+    }
+
+    /// Appends a move made at the given time since the start of the game.
+    pub fn push(&mut self, time: Duration, placement: &Placement) {
+        self.moves.push(TimedMove {time, placement: placement.clone()});
+    }
+
+    /// Stores the rows of the final board.
+    pub fn finish(&mut self, board: &GameBoard) {
+        self.board = Some(board.to_rows());
+    }
+
+    /// The board before any moves
+    pub fn initial_board(&self) -> std::result::Result<GameBoard, String> {
+        let block = block_from_cells(&self.roll)?;
+        return Ok(GameBoard::new(&block));
+    }
+
+    /// Rebuilds the board step by step,
+    /// i.e. returns the initial board followed by the board after each move.
+    pub fn replay(&self) -> std::result::Result<Vec<GameBoard>, String> {
+        let mut board = self.initial_board()?;
+        let mut boards = vec![board.clone()];
+        for (k, step) in self.moves.iter().enumerate() {
+            let placement = &step.placement;
+            placement
+                .to_piece()
+                .and_then(|piece| board.place_piece(&piece))
+                .map_err(|err| format!("Move {} ({placement}): {err}", k + 1))?;
+            boards.push(board.clone());
+        }
+        if let Some(rows) = &self.board {
+            if *rows != board.to_rows() {
+                return Err("The final board does not agree with the moves.".to_string());
+            }
+        }
+        return Ok(boards);
+    }
+
+    /// The board after all moves
+    pub fn final_board(&self) -> std::result::Result<GameBoard, String> {
+        let boards = self.replay()?;
+        return Ok(boards.last().unwrap().clone());
+    }
+
+    /// Records the pieces of a board as moves (in the order of the pieces, at time `0`).
+    pub fn from_board(roll: &[String], seed: Option<String>, board: &GameBoard) -> std::result::Result<Self, String> {
+        let mut record = Self::new(roll, seed);
+        for (kind, _) in board.to_placements() {
+            let piece = &board.get_pieces()[&kind];
+            let placement = Placement::from_piece(piece)
+                .ok_or_else(|| format!("Piece {} has the wrong shape.", kind.as_str()))?;
+            record.push(Duration::ZERO, &placement);
+        }
+        record.finish(board);
+        return Ok(record);
+    }
+}
+
+impl Display for GameRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "roll {}", self.roll.join(" "))?;
+        if let Some(seed) = &self.seed {
+            writeln!(f, "seed {seed}")?;
+        }
+        for step in self.moves.iter() {
+            writeln!(f, "move {:.3} {}", step.time.as_secs_f64(), step.placement)?;
+        }
+        if let Some(rows) = &self.board {
+            writeln!(f, "board")?;
+            for row in rows.iter() {
+                // NOTE: empty cells are written as dots, so that rows are not trimmed
+                writeln!(f, "{}", row.replace(' ', "."))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for GameRecord {
+    type Err = String;
+
+    fn from_str(text: &str) -> std::result::Result<Self, Self::Err> {
+        let mut record = Self::default();
+        let mut lines = text
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .peekable();
+        while let Some(line) = lines.next() {
+            let (keyword, value) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            match keyword {
+                "roll" => {
+                    record.roll = value.split(|c: char| c == ',' || c.is_whitespace())
+                        .filter(|face| !face.is_empty())
+                        .map(|face| face.to_uppercase())
+                        .collect();
+                },
+                "seed" => {
+                    record.seed = Some(value.trim().to_string());
+                },
+                "move" => {
+                    let (time, placement) = value
+                        .trim()
+                        .split_once(char::is_whitespace)
+                        .ok_or_else(|| format!("Move {line} must be of the form move SECONDS PLACEMENT."))?;
+                    let time = time
+                        .parse()
+                        .ok()
+                        .and_then(|secs: f64| Duration::try_from_secs_f64(secs).ok())
+                        .ok_or_else(|| format!("Invalid time {time}."))?;
+                    record.push(time, &placement.parse()?);
+                },
+                "board" => {
+                    // NOTE: rows are the only entries without whitespace
+                    let mut rows: Vec<String> = vec![];
+                    while let Some(row) = lines.next_if(|line| !line.contains(char::is_whitespace) && *line != "board") {
+                        rows.push(row.replace('.', " "));
+                    }
+                    record.board = Some(rows);
+                },
+                _ => {
+                    return Err(format!("Unknown entry {keyword}."));
+                },
+            }
+        }
+        if record.roll.is_empty() {
+            return Err("The record has no roll.".to_string());
+        }
+        // the board has the shape of the blocking piece of the roll
+        if let Some(rows) = &record.board {
+            let (m, n) = record.initial_board()?.get_shape();
+            if rows.len() != m || rows.iter().any(|row| row.chars().count() != n) {
+                return Err(format!("The board must consist of {m} rows of {n} cells."));
+            }
+        }
+        return Ok(record);
+    }
+}
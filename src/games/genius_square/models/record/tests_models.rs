@@ -0,0 +1,142 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use rstest::rstest;
+use std::time::Duration;
+
+use super::models::*;
+use crate::games::genius_square::algorithms::validate::validate_solution;
+use crate::games::genius_square::models::board::methods::board_from_notation;
+use crate::games::genius_square::models::board::methods::board_from_placements;
+use crate::games::genius_square::models::board::methods::board_from_grid;
+use crate::games::genius_square::models::board::methods::board_from_text;
+use crate::games::genius_square::models::board::methods::board_to_notation;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::geometry::d4::D4;
+use crate::games::genius_square::tests_support::SOLUTION;
+use crate::games::genius_square::tests_support::create_board;
+use crate::games::genius_square::tests_support::create_roll;
+use crate::games::genius_square::tests_support::embed_board;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+/// bundle of tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest]
+    #[case("L:A2:r2:h", "L=A2,A3,A4,B4")]
+    #[case("Z:E1:r1:h", "Z=E1,D2,E2,D3")]
+    #[case("T:E3:r2:h", "T=E3,D4,E4,F4")]
+    #[case("C:E5:r2:h", "C=E5,E6,F6")]
+    #[case("4:A5", "4=A5,B5,C5,D5")]
+    fn test_placement(#[case] text: &str, #[case] cells: &str) {
+        let block = create_board("").get_block().clone();
+        let placement: Placement = text.parse().unwrap();
+        let expected = board_from_placements(cells, &block).unwrap();
+        let piece = &expected.get_pieces()[&placement.kind];
+        assert_eq!(placement.to_piece().unwrap().to_coords(), piece.to_coords());
+        assert_eq!(Placement::from_piece(piece), Some(placement.clone()));
+        assert_eq!(placement.to_string(), text);
+    }
+
+    #[test]
+    fn test_canonical_form() {
+        // the square looks the same in every orientation
+        let placement: Placement = "x:b2:r2".parse().unwrap();
+        assert_eq!(placement.to_string(), "X:B2");
+        assert_eq!(placement.to_piece().unwrap().to_coords(), vec![(1, 1), (1, 2), (2, 1), (2, 2)]);
+        // a quarter turn of the bar followed by a flip is a quarter turn
+        let placement = Placement::new(&EnumPiece::Symb3, (0, 5), &(D4::hflip() * D4::rotation(1)));
+        assert_eq!(placement.to_string(), "3:F1:r1");
+        assert!("3:F1".parse::<Placement>().unwrap().to_piece().is_err());
+        assert!("Q:A1".parse::<Placement>().is_err());
+        assert!("L:G1".parse::<Placement>().unwrap().to_piece().is_err());
+        assert!("L:1A".parse::<Placement>().is_err());
+        assert!("L:A1:r1:v".parse::<Placement>().is_err());
+    }
+
+    #[test]
+    fn test_notation_of_board() {
+        let board = create_board("");
+        let solution = create_board(SOLUTION);
+        let notation = board_to_notation(&solution);
+        assert_eq!(notation.split("; ").count(), 9);
+        let parsed = board_from_notation(&notation, board.get_block()).unwrap();
+        assert_eq!(parsed.to_rows(), solution.to_rows());
+        assert_eq!(board_from_text(&notation, board.get_block()).unwrap().to_rows(), solution.to_rows());
+        assert!(board_from_notation("X:B2; X:C3", board.get_block()).is_err());
+    }
+
+    #[test]
+    fn test_notation_on_large_board() {
+        let solution = create_board(SOLUTION);
+        let solution = embed_board(&solution, 12, 12);
+        let block = solution.get_block();
+
+        // cells are labelled on the large board
+        let notation = board_to_notation(&solution);
+        assert!(notation.starts_with("1:G7; "), "{notation}");
+        assert_eq!(board_from_notation(&notation, block).unwrap().to_rows(), solution.to_rows());
+        let placements: Vec<String> = solution
+            .to_placements()
+            .iter()
+            .map(|(kind, cells)| format!("{}={}", kind.as_str(), cells.join(",")))
+            .collect();
+        assert!(placements.contains(&"X=H8,I8,H9,I9".to_string()));
+        assert_eq!(board_from_placements(&placements.join("; "), block).unwrap().to_rows(), solution.to_rows());
+        assert_eq!(board_from_grid(&solution.to_rows().join("\n"), block).unwrap().to_rows(), solution.to_rows());
+        assert!(board_from_placements("X=M1,M2,N1,N2", block).is_err());
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let solution = create_board(SOLUTION);
+        let mut record = GameRecord::from_board(&create_roll(), Some("abc".to_string()), &solution).unwrap();
+        for (k, step) in record.moves.iter_mut().enumerate() {
+            step.time = Duration::from_millis(1500 * (k as u64 + 1));
+        }
+
+        let text = record.to_string();
+        assert!(text.starts_with("roll B1 C4 D6 F1 F2 F3 F5\nseed abc\nmove 1.500 1:A1\n"));
+        let parsed: GameRecord = text.parse().unwrap();
+        assert_eq!(parsed, record);
+
+        let boards = parsed.replay().unwrap();
+        assert_eq!(boards.len(), 10);
+        assert_eq!(boards[0].get_pieces().len(), 0);
+        assert_eq!(boards[3].get_pieces().len(), 3);
+        assert!(validate_solution(boards.last().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_record_errors() {
+        let text = "roll B1 C4 D6 F1 F2 F3 F5\nmove 1 X:B2\n";
+        let record: GameRecord = text.parse().unwrap();
+        assert_eq!(record.final_board().unwrap().get_pieces().len(), 1);
+        // an unfinished board is written with dots
+        let mut record = record.clone();
+        record.finish(&record.final_board().unwrap());
+        assert!(record.to_string().contains("\nboard\n.■...■\n.XX..■\n"));
+        assert_eq!(record.to_string().parse::<GameRecord>().unwrap(), record);
+
+        // collisions with the block, repeated pieces and disagreeing boards
+        for text in [
+            "roll B1 C4 D6 F1 F2 F3 F5\nmove 1 X:A1\n",
+            "roll B1 C4 D6 F1 F2 F3 F5\nmove 1 X:B2\nmove 2 X:D2\n",
+            "roll B1 C4 D6 F1 F2 F3 F5\nmove 1 X:B2\nboard\n......\n......\n......\n......\n......\n......\n",
+        ] {
+            assert!(text.parse::<GameRecord>().unwrap().replay().is_err(), "{text}");
+        }
+        assert!("roll B1 C4 D6 F1 F2 F3 F5\nboard\n......\n......\n".parse::<GameRecord>().is_err());
+        assert!("move 1 X:B2\n".parse::<GameRecord>().is_err());
+        assert!("roll B1\nmove -1 X:B2\n".parse::<GameRecord>().is_err());
+        assert!("roll B1\nmove 1e300 X:B2\n".parse::<GameRecord>().is_err());
+        assert!("roll B1\nmove NaN X:B2\n".parse::<GameRecord>().is_err());
+        assert!("roll B1\nplay X:B2\n".parse::<GameRecord>().is_err());
+    }
+}
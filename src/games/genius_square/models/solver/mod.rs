@@ -0,0 +1,3 @@
+/// Models for controlling and reporting on solver runs
+
+pub mod models;
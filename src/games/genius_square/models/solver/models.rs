@@ -0,0 +1,325 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::pieces::models::Piece;
+use crate::models::tree::base::GenericTree;
+use crate::models::tree::base::GenericTreeLike;
+use crate::models::tree::base::GenericTreeOrRoot;
+
+/// ----------------------------------------------------------------
+/// STRUCTS
+/// ----------------------------------------------------------------
+
+/// Limits imposed on a single run of the solver.
+///
+/// All limits are optional; by default the search runs to completion.
+#[derive(Clone, Debug, Default)]
+pub struct SolveOptions {
+    deadline: Option<Instant>,
+    max_nodes: Option<u64>,
+    cancel: Option<Arc<AtomicBool>>,
+    progress: bool,
+}
+
+/// Final state of a solver run.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SolveStatus {
+    /// A solution was found.
+    Solved,
+    /// The search finished and no solution exists.
+    Exhausted,
+    /// The deadline passed before the search finished.
+    TimedOut,
+    /// The maximal number of nodes was visited before the search finished.
+    NodeLimit,
+    /// The cancellation flag was raised before the search finished.
+    Cancelled,
+    /// The solver cannot handle the board (e.g. because it is too large).
+    Unsupported,
+}
+
+/// Statistics collected during a solver run.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SolveStats {
+    pub nodes: u64,
+    pub solutions: u64,
+    pub elapsed: Duration,
+}
+
+/// Result of a solver run.
+///
+/// The board is the first solution found, if any.
+#[derive(Clone, Debug)]
+pub struct SolveOutcome {
+    pub status: SolveStatus,
+    pub board: Option<GameBoard>,
+    pub stats: SolveStats,
+}
+
+/// Outcomes of several strategies on the same game state.
+#[derive(Clone, Debug, Default)]
+pub struct SolverComparison {
+    /// name of each strategy with the outcome of its search for all solutions
+    pub runs: Vec<(&'static str, SolveOutcome)>,
+    /// descriptions of the disagreements between the strategies
+    pub disagreements: Vec<String>,
+}
+
+/// Node of the search tree explored by a solver,
+/// i.e. a placement tried during the search (the root stands for the initial board).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SearchNode {
+    /// the piece placed (none for the root)
+    pub piece: Option<EnumPiece>,
+    /// the cells covered by the piece in notation, e.g. `A2`
+    pub cells: Vec<String>,
+    /// whether a solution was found in the subtree
+    pub success: bool,
+    /// number of nodes in the subtree which were not recorded, due to the depth limit
+    pub unrecorded: u64,
+}
+
+/// Records the search tree explored by a solver (see [`SearchNode`]) up to a maximal depth,
+/// where the root has depth `0`.
+///
+/// By default nothing is recorded, so that the searches do not allocate.
+#[derive(Clone, Default)]
+pub struct SearchTreeRecorder {
+    max_depth: usize,
+    /// the nodes along the current branch, starting with the root
+    stack: Vec<GenericTree<SearchNode>>,
+}
+
+/// Strategy for solving game states.
+///
+/// All methods respect the limits in the options,
+/// and the statistics of their outcomes count the solutions found.
+/// Strategies are registered in
+/// [`SOLVERS`](crate::games::genius_square::algorithms::solvers::SOLVERS).
+pub trait Solver: Debug + Send + Sync {
+    /// Name used to select the strategy (e.g. on the command line).
+    fn name(&self) -> &'static str;
+
+    /// One-line description of the strategy.
+    fn description(&self) -> &'static str;
+
+    /// Searches for a solution.
+    fn solve(&self, board: &GameBoard, options: &SolveOptions) -> SolveOutcome;
+
+    /// Searches for all solutions,
+    /// where the board of the outcome is the first solution found.
+    fn solve_all(&self, board: &GameBoard, options: &SolveOptions) -> (SolveOutcome, Vec<GameBoard>);
+
+    /// Counts all solutions (contained in the statistics of the outcome).
+    fn count(&self, board: &GameBoard, options: &SolveOptions) -> SolveOutcome {
+        let (outcome, _) = self.solve_all(board, options);
+        return outcome;
+    }
+
+    /// Searches for a solution and records the explored search tree
+    /// up to the given depth (unlimited if none), if the strategy supports it.
+    fn solve_with_tree(
+        &self,
+        _board: &GameBoard,
+        _options: &SolveOptions,
+        _max_depth: Option<usize>,
+    ) -> std::result::Result<(SolveOutcome, GenericTree<SearchNode>), String> {
+        return Err(format!("The solver {} does not record search trees.", self.name()));
+    }
+}
+
+/// ----------------------------------------------------------------
+/// IMPLEMENTATIONS
+/// ----------------------------------------------------------------
+
+impl SolveOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops the search once the given instant has passed.
+    #[allow(unused)]
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        return self;
+    }
+
+    /// Stops the search once the given time has elapsed, counted from now.
+    ///
+    /// A timeout too long to be represented as an instant imposes no deadline.
+    #[allow(unused)]
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.with_deadline(deadline),
+            None => self,
+        }
+    }
+
+    /// Stops the search once the given number of nodes have been visited.
+    #[allow(unused)]
+    pub fn with_max_nodes(mut self, max_nodes: u64) -> Self {
+        self.max_nodes = Some(max_nodes);
+        return self;
+    }
+
+    /// Stops the search as soon as the shared flag is set to `true`.
+    #[allow(unused)]
+    pub fn with_cancel(mut self, cancel: Arc<AtomicBool>) -> Self {
+        self.cancel = Some(cancel);
+        return self;
+    }
+
+    /// Displays a progress bar during the search.
+    pub fn with_progress(mut self, progress: bool) -> Self {
+        self.progress = progress;
+        return self;
+    }
+
+    pub fn get_progress(&self) -> bool {
+        self.progress
+    }
+
+    /// Determines whether the search has to be aborted,
+    /// given the number of nodes visited so far.
+    pub fn check_limits(&self, nodes: u64) -> Option<SolveStatus> {
+        if let Some(cancel) = &self.cancel {
+            if cancel.load(Ordering::Relaxed) {
+                return Some(SolveStatus::Cancelled);
+            }
+        }
+        if let Some(max_nodes) = self.max_nodes {
+            if nodes >= max_nodes {
+                return Some(SolveStatus::NodeLimit);
+            }
+        }
+        if let Some(deadline) = self.deadline {
+            if Instant::now() >= deadline {
+                return Some(SolveStatus::TimedOut);
+            }
+        }
+        return None;
+    }
+}
+
+impl SolveStatus {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            SolveStatus::Solved => "solved",
+            SolveStatus::Exhausted => "exhausted",
+            SolveStatus::TimedOut => "timed out",
+            SolveStatus::NodeLimit => "node limit reached",
+            SolveStatus::Cancelled => "cancelled",
+            SolveStatus::Unsupported => "unsupported",
+        }
+    }
+
+    /// Whether the search ran to completion (as opposed to giving up).
+    #[allow(unused)]
+    pub fn is_finished(&self) -> bool {
+        matches!(self, SolveStatus::Solved | SolveStatus::Exhausted)
+    }
+}
+
+impl SolveOutcome {
+    #[allow(unused)]
+    pub fn is_solved(&self) -> bool {
+        self.status == SolveStatus::Solved
+    }
+}
+
+impl SearchNode {
+    /// The node of a placement tried during the search
+    pub fn from_piece(piece: &Piece) -> Self {
+        let geometry = piece.get_positions().get_geometry();
+        let cells = piece.to_coords().iter().map(|&cell| geometry.label(cell)).collect();
+        return Self {piece: Some(piece.get_kind()), cells, ..Self::default()};
+    }
+}
+
+impl SearchTreeRecorder {
+    /// Records nodes up to the given depth (unlimited if none).
+    pub fn new(max_depth: Option<usize>) -> Self {
+        let root = GenericTree::new(SearchNode::default(), None);
+        return Self {max_depth: max_depth.unwrap_or(usize::MAX), stack: vec![root]};
+    }
+
+    /// Whether the placements tried at the given depth of the search (numbered from `0`) are recorded,
+    /// i.e. the nodes below a node of that depth
+    pub fn records(&self, depth: usize) -> bool {
+        !self.stack.is_empty() && depth < self.max_depth
+    }
+
+    /// Opens a node below the current node.
+    pub fn open(&mut self, node: SearchNode) {
+        self.stack.push(GenericTree::new(node, None));
+    }
+
+    /// Closes the current node opened at the given depth,
+    /// given whether a solution was found below it and the number of nodes explored below it.
+    pub fn close(&mut self, depth: usize, success: bool, nodes: u64) {
+        let mut tree = self.stack.pop().expect("no node has been opened");
+        tree.root.success = success;
+        if depth + 1 == self.max_depth {
+            tree.root.unrecorded = nodes;
+        }
+        self.stack.last_mut().expect("the root cannot be closed").add(GenericTreeOrRoot::Tree(tree));
+    }
+
+    /// The recorded tree (if any),
+    /// given whether a solution was found and the number of nodes explored.
+    pub fn finish(mut self, success: bool, nodes: u64) -> Option<GenericTree<SearchNode>> {
+        let mut tree = self.stack.pop()?;
+        tree.root.success = success;
+        if self.max_depth == 0 {
+            tree.root.unrecorded = nodes;
+        }
+        return Some(tree);
+    }
+}
+
+impl SolverComparison {
+    pub fn agrees(&self) -> bool {
+        self.disagreements.is_empty()
+    }
+}
+
+impl Display for SolveStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Prints the placement as in `L=A2,A3,A4,B4 ✓`,
+/// followed by the number of unrecorded nodes (if any).
+impl Display for SearchNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match &self.piece {
+            Some(kind) => write!(f, "{}={}", kind.as_str(), self.cells.join(","))?,
+            None => write!(f, "start")?,
+        }
+        write!(f, " {}", if self.success { "✓" } else { "✗" })?;
+        if self.unrecorded > 0 {
+            write!(f, " (+{} nodes)", self.unrecorded)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for SolveStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{} nodes in {:.2?}", self.nodes, self.elapsed)
+    }
+}
@@ -0,0 +1,59 @@
+/// Fixtures shared by the tests of the engine.
+
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use crate::games::genius_square::models::board::methods::board_from_placements;
+use crate::games::genius_square::models::board::models::GameBoard;
+use crate::games::genius_square::models::constants::enums::EnumPiece;
+use crate::games::genius_square::models::dice::methods::block_from_roll;
+use crate::games::genius_square::models::geometry::models::SQUARE_GRID;
+use crate::games::genius_square::models::pieces::models::Piece;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+/// Roll used throughout the tests
+pub const ROLL: &[&str] = &["B1", "C4", "D6", "F1", "F2", "F3", "F5"];
+/// A solution of [`ROLL`] given as placements
+pub const SOLUTION: &str = "1=A1; 2=C1,D1; Z=E1,D2,E2,D3; L=A2,A3,A4,B4; X=B2,C2,B3,C3; T=E3,D4,E4,F4; 4=A5,B5,C5,D5; C=E5,E6,F6; 3=A6,B6,C6";
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Faces of [`ROLL`]
+pub fn create_roll() -> Vec<String> {
+    return ROLL.iter().map(|x| x.to_string()).collect();
+}
+
+/// Board of [`ROLL`] with the given placements, e.g. `"X=B2,C2,B3,C3"`
+/// (empty if no placements are given).
+pub fn create_board(placements: &str) -> GameBoard {
+    let block = block_from_roll(&create_roll());
+    if placements.is_empty() {
+        return GameBoard::new(&block);
+    }
+    return board_from_placements(placements, &block).unwrap();
+}
+
+/// Places a board in the bottom right corner of a larger `m x n` board,
+/// on which all other cells are blocked.
+pub fn embed_board(board: &GameBoard, m: usize, n: usize) -> GameBoard {
+    let (m0, n0) = board.get_shape();
+    let (di, dj) = (m - m0, n - n0);
+    let shift = |piece: &Piece| piece.to_coords().iter().map(|&(i, j)| (i + di, j + dj)).collect::<Vec<_>>();
+    let mut blocked: Vec<(usize, usize)> = (0..m * n)
+        .map(|k| (k / n, k % n))
+        .filter(|&(i, j)| i < di || j < dj)
+        .collect();
+    blocked.extend(shift(board.get_block()));
+    let block = Piece::from_coords_on(&SQUARE_GRID, blocked, m, n, Some(EnumPiece::Block));
+    let mut result = GameBoard::new(&block);
+    for (kind, piece) in board.get_pieces() {
+        result.add_piece(kind, &Piece::from_coords_on(&SQUARE_GRID, shift(piece), m, n, Some(kind.clone())));
+    }
+    return result;
+}
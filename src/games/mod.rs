@@ -0,0 +1,3 @@
+/// Engines for (puzzle) games.
+
+pub mod genius_square;
@@ -1,6 +1,8 @@
 /// Modules available from General crate for other crates
 
 pub mod app;
+pub mod bindings;
 pub mod _core;
+pub mod games;
 pub mod models;
 pub mod problems;
@@ -1,5 +1,7 @@
 /// Modules available for General code base
 
 pub mod app;
+pub mod bindings;
+pub mod games;
 pub mod models;
 pub mod problems;
@@ -0,0 +1,51 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+extern crate general;
+
+use general::_core::alloc::CountingAllocator;
+use general::_core::alloc::allocation_count;
+use general::games::genius_square::*;
+use general::games::genius_square::algorithms::solve::count_brute_force_with_options;
+
+mod support;
+use support::create_board;
+
+/// ----------------------------------------------------------------
+/// GLOBALS
+/// ----------------------------------------------------------------
+
+// NOTE: a target of its own, so that no other tests allocate concurrently
+#[global_allocator]
+static ALLOC: CountingAllocator = CountingAllocator;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+#[test]
+fn test_brute_force_search_does_not_allocate() {
+    let board = create_board();
+
+    // the searches only differ in the number of nodes visited
+    let (small, nodes_small) = count_allocations(&board, 1_000);
+    let (large, nodes_large) = count_allocations(&board, 200_000);
+    assert_eq!((nodes_small, nodes_large), (1_000, 200_000));
+    // up to the first solution, which is recorded in the larger search only
+    assert!(large <= small + 100, "{small} allocations for 1k nodes vs. {large} for 200k nodes");
+}
+
+/// ----------------------------------------------------------------
+/// AUXILIARY
+/// ----------------------------------------------------------------
+
+/// Number of allocations while counting the solutions (up to a node budget)
+/// together with the number of nodes visited
+fn count_allocations(board: &GameBoard, max_nodes: u64) -> (u64, u64) {
+    let options = SolveOptions::new().with_max_nodes(max_nodes);
+    let before = allocation_count().unwrap_or(0);
+    let outcome = count_brute_force_with_options(board, &options);
+    let after = allocation_count().expect("the counting allocator is registered");
+    return (after - before, outcome.stats.nodes);
+}
@@ -0,0 +1,35 @@
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use rstest::rstest;
+
+use general::_core::rand::seed_rng;
+use general::games::genius_square::*;
+
+use crate::support::create_board;
+
+/// ----------------------------------------------------------------
+/// TESTS
+/// ----------------------------------------------------------------
+
+#[rstest]
+#[case("1234")]
+#[case("genius")]
+fn test_roll_is_reproducible(#[case] seed: &str) {
+    let faces1 = roll_dice(&mut seed_rng(Some(seed.to_string())));
+    let faces2 = roll_dice(&mut seed_rng(Some(seed.to_string())));
+    assert_eq!(faces1, faces2);
+    let block = block_from_roll(&faces1);
+    assert_eq!(block.get_weight(), 7);
+}
+
+#[test]
+fn test_solve_and_validate() {
+    let board = create_board();
+    assert!(validate_solution(&board).is_err());
+    let options = SolveOptions::new().with_max_nodes(1_000_000);
+    let outcome = solve_brute_force_with_options(&board, &options);
+    assert_eq!(outcome.status, SolveStatus::Solved);
+    assert_eq!(validate_solution(&outcome.board.unwrap()), Ok(()));
+}
@@ -2,3 +2,5 @@ extern crate general;
 extern crate rstest;
 
 // pub mod path_to_testmodule;
+pub mod genius_square;
+pub mod support;
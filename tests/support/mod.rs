@@ -0,0 +1,24 @@
+/// Fixtures shared by the integration tests.
+
+/// ----------------------------------------------------------------
+/// IMPORTS
+/// ----------------------------------------------------------------
+
+use general::games::genius_square::*;
+
+/// ----------------------------------------------------------------
+/// CONSTANTS
+/// ----------------------------------------------------------------
+
+/// Roll used throughout the tests
+pub const ROLL: &[&str] = &["B1", "C4", "D6", "F1", "F2", "F3", "F5"];
+
+/// ----------------------------------------------------------------
+/// METHODS
+/// ----------------------------------------------------------------
+
+/// Empty board of [`ROLL`]
+pub fn create_board() -> GameBoard {
+    let faces: Vec<String> = ROLL.iter().map(|x| x.to_string()).collect();
+    return GameBoard::new(&block_from_roll(&faces));
+}